@@ -0,0 +1,53 @@
+use common::{ParseError, Part, Solution};
+use visual::Canvas;
+
+/// Days with an animation.
+pub const DAYS: [u8; 3] = [9, 10, 14];
+
+/// Plays a day on the canvas. Part 2 animates the ten knot rope on day 9
+/// and the cave with a floor on day 14. Day 10 draws the same screen for
+/// both parts.
+/// Returns `None` if there is no animation for that day.
+pub fn animate(
+    day: u8,
+    part: Part,
+    input: &str,
+    canvas: &mut impl Canvas,
+) -> Option<Result<(), ParseError>> {
+    let animated = match day {
+        9 => day09::Day09.parse(input).map(|moves| {
+            let tail_count = match part {
+                Part::One => 1,
+                Part::Two => 9,
+            };
+            day09::animate(&moves, tail_count, canvas)
+        }),
+        10 => day10::Day10
+            .parse(input)
+            .map(|program| day10::animate(&program, canvas)),
+        14 => day14::Day14
+            .parse(input)
+            .map(|paths| day14::animate(&paths, part == Part::Two, canvas)),
+        _ => return None,
+    };
+    Some(animated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use visual::Recorder;
+
+    #[test]
+    fn animate_test() {
+        let mut recorder = Recorder::default();
+        let animated = animate(9, Part::Two, "R 4\nU 4\n", &mut recorder);
+        assert_eq!(animated, Some(Ok(())));
+        assert_eq!(recorder.frames.len(), 8);
+
+        assert!(animate(9, Part::One, "X 4\n", &mut recorder)
+            .unwrap()
+            .is_err());
+        assert!(animate(1, Part::One, "1\n", &mut recorder).is_none());
+    }
+}
@@ -0,0 +1,81 @@
+use common::{ParseError, Solution};
+use serde::Serialize;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use tracing::{debug, debug_span};
+
+/// Fastest, median and slowest of a set of timed runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+fn nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Timings of one day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = black_box(f());
+    (value, start.elapsed())
+}
+
+/// Times parsing and both parts, once per run.
+pub fn run<S: Solution>(solution: &S, input: &str, runs: u32) -> Result<Timings, ParseError> {
+    let mut parse = vec![];
+    let mut part_1 = vec![];
+    let mut part_2 = vec![];
+
+    for run in 1..=runs {
+        let _span = debug_span!("run", run).entered();
+        let (parsed, elapsed) = time(|| solution.parse(input));
+        let parsed = parsed?;
+        parse.push(elapsed);
+        part_1.push(time(|| solution.part_1(&parsed)).1);
+        part_2.push(time(|| solution.part_2(&parsed)).1);
+        debug!(parse = ?parse[parse.len() - 1], part_1 = ?part_1[part_1.len() - 1], part_2 = ?part_2[part_2.len() - 1], "timed");
+    }
+
+    Ok(Timings {
+        parse: Stats::from_samples(parse),
+        part_1: Stats::from_samples(part_1),
+        part_2: Stats::from_samples(part_2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_test() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(9), ms(3), ms(4)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(4), ms(9)));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::days::{self, SolveError};
+use common::{parse, Answer, Part, Solved};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Answers stored on disk, one file per day, part, solution version and
+/// input. Inputs are hashed after normalising line endings, so an input
+/// saved with CRLF line endings shares answers with the same input with LF.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+/// What an answer is stored under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Key {
+    pub day: u8,
+    pub part: Part,
+    pub version: u32,
+    pub input_hash: u128,
+}
+
+impl Key {
+    /// Returns `None` if there is no solution for the day.
+    pub fn new(day: u8, part: Part, input: &str) -> Option<Key> {
+        Some(Key {
+            day,
+            part,
+            version: days::version(day)?,
+            input_hash: fnv1a(parse::normalize(input).as_bytes()),
+        })
+    }
+}
+
+// What's in a cache file: the answer and how long it took when it was solved
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    answer: Answer,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+// 128 bit FNV-1a. Unlike std's hashers its output never changes between
+// Rust versions, which matters for hashes kept on disk
+fn fnv1a(bytes: &[u8]) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u128).wrapping_mul(PRIME)
+    })
+}
+
+fn day_dir(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Cache {
+        Cache { dir: dir.into() }
+    }
+
+    fn path(&self, key: &Key) -> PathBuf {
+        self.dir.join(day_dir(key.day)).join(format!(
+            "part{}-v{}-{:032x}.json",
+            key.part, key.version, key.input_hash
+        ))
+    }
+
+    /// The stored answer, if there is one. Unreadable entries count as missing.
+    pub fn get(&self, key: &Key) -> Option<Solved> {
+        let path = self.path(key);
+        let contents = fs::read_to_string(&path).ok()?;
+        let entry: Entry = match serde_json::from_str(&contents) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "ignoring a broken cache entry");
+                return None;
+            }
+        };
+
+        Some(Solved {
+            answer: entry.answer,
+            parse_time: Duration::from_nanos(entry.parse_ns),
+            solve_time: Duration::from_nanos(entry.solve_ns),
+        })
+    }
+
+    /// Stores an answer, replacing any answer already stored under the key.
+    pub fn put(&self, key: &Key, solved: &Solved) -> io::Result<()> {
+        let path = self.path(key);
+        fs::create_dir_all(path.parent().unwrap())?;
+
+        let entry = Entry {
+            answer: solved.answer.clone(),
+            parse_ns: solved.parse_time.as_nanos() as u64,
+            solve_ns: solved.solve_time.as_nanos() as u64,
+        };
+        // Write then rename, so a reader never sees half an entry
+        let partial = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&partial, serde_json::to_string(&entry).unwrap())?;
+        fs::rename(&partial, &path)
+    }
+
+    /// Removes every stored answer for the given days. Returns how many were removed.
+    pub fn clear(&self, days: impl IntoIterator<Item = u8>) -> io::Result<usize> {
+        let mut removed = 0;
+        for day in days {
+            let dir = self.dir.join(day_dir(day));
+            removed += match fs::read_dir(&dir) {
+                Ok(entries) => entries.filter_map(Result::ok).count(),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            fs::remove_dir_all(&dir)?;
+        }
+        Ok(removed)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+/// Like `days::solve`, but answers from `cache` when it can and stores new
+/// answers in it. The flag is true if the answer came from the cache.
+pub fn solve(
+    cache: Option<&Cache>,
+    day: u8,
+    part: Part,
+    input: &str,
+) -> Option<Result<(Solved, bool), SolveError>> {
+    let (cache, key) = match cache.zip(Key::new(day, part, input)) {
+        Some(found) => found,
+        None => return days::solve(day, part, input).map(|result| result.map(|s| (s, false))),
+    };
+
+    if let Some(solved) = cache.get(&key) {
+        debug!(day, %part, "answer from the cache");
+        return Some(Ok((solved, true)));
+    }
+
+    let result = days::solve(day, part, input)?;
+    if let Ok(solved) = &result {
+        if let Err(e) = cache.put(&key, solved) {
+            warn!(dir = %cache.dir().display(), error = %e, "could not cache the answer");
+        }
+    }
+    Some(result.map(|solved| (solved, false)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    // A fresh cache directory for each test
+    fn temp_cache(name: &str) -> Cache {
+        let dir = env::temp_dir().join(format!("aoc-cache-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn fnv1a_test() {
+        assert_eq!(fnv1a(b""), 0x6c62272e07bb014262b821756295c58d);
+        assert_eq!(fnv1a(b"a"), 0xd228cb696f1a8caf78912b704e4a8964);
+    }
+
+    #[test]
+    fn key_test() {
+        let key = Key::new(1, Part::One, "1\n2\n").unwrap();
+        assert_eq!(Key::new(1, Part::One, "1\r\n2\r\n"), Some(key));
+        assert_ne!(Key::new(1, Part::One, "1\n3\n"), Some(key));
+        assert_eq!(Key::new(99, Part::One, "1\n2\n"), None);
+    }
+
+    #[test]
+    fn solve_test() {
+        let cache = temp_cache("solve");
+        let input = "1\n2\n\n3\n\n4\n";
+
+        let (solved, hit) = solve(Some(&cache), 1, Part::Two, input).unwrap().unwrap();
+        assert_eq!((solved.answer.to_string(), hit), ("10".to_string(), false));
+
+        let (cached, hit) = solve(Some(&cache), 1, Part::Two, input).unwrap().unwrap();
+        assert_eq!((cached, hit), (solved, true));
+
+        // Failures aren't cached
+        assert!(solve(Some(&cache), 1, Part::One, "x\n").unwrap().is_err());
+        assert_eq!(cache.clear([1, 2]).unwrap(), 1);
+        assert!(!cache.dir().join("day01").exists());
+
+        let (_, hit) = solve(Some(&cache), 1, Part::Two, input).unwrap().unwrap();
+        assert!(!hit);
+        let _ = fs::remove_dir_all(cache.dir());
+    }
+
+    #[test]
+    fn broken_entry_test() {
+        let cache = temp_cache("broken");
+        let key = Key::new(1, Part::One, "1\n").unwrap();
+        fs::create_dir_all(cache.path(&key).parent().unwrap()).unwrap();
+        fs::write(cache.path(&key), "{").unwrap();
+
+        assert_eq!(cache.get(&key), None);
+        let _ = fs::remove_dir_all(cache.dir());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::bench::{self, Timings};
+use crate::panics;
+use common::{ParseError, Part, Solution, Solved};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use tracing::info_span;
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 15;
+
+// Evaluates `$body` with `$solution` bound to the given day's solution,
+// or returns `None` if there is no solution for that day
+macro_rules! with_solution {
+    ($day:expr, |$solution:ident| $body:expr) => {
+        match $day {
+            1 => Some({
+                let $solution = &day01::Day01;
+                $body
+            }),
+            2 => Some({
+                let $solution = &day02::Day02;
+                $body
+            }),
+            3 => Some({
+                let $solution = &day03::Day03;
+                $body
+            }),
+            4 => Some({
+                let $solution = &day04::Day04;
+                $body
+            }),
+            5 => Some({
+                let $solution = &day05::Day05;
+                $body
+            }),
+            6 => Some({
+                let $solution = &day06::Day06;
+                $body
+            }),
+            7 => Some({
+                let $solution = &day07::Day07;
+                $body
+            }),
+            8 => Some({
+                let $solution = &day08::Day08;
+                $body
+            }),
+            9 => Some({
+                let $solution = &day09::Day09;
+                $body
+            }),
+            10 => Some({
+                let $solution = &day10::Day10;
+                $body
+            }),
+            11 => Some({
+                let $solution = &day11::Day11;
+                $body
+            }),
+            12 => Some({
+                let $solution = &day12::Day12;
+                $body
+            }),
+            13 => Some({
+                let $solution = &day13::Day13;
+                $body
+            }),
+            14 => Some({
+                let $solution = &day14::Day14;
+                $body
+            }),
+            15 => Some({
+                let $solution = &day15::Day15::default();
+                $body
+            }),
+            _ => None,
+        }
+    };
+}
+
+/// Why a day couldn't be solved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Panic(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+/// Runs a single part of a day on the given puzzle input.
+/// Returns `None` if there is no solution for that day.
+pub fn solve(day: u8, part: Part, input: &str) -> Option<Result<Solved, SolveError>> {
+    with_solution!(day, |solution| solve_with(solution, day, part, input))
+}
+
+/// Runs a single part of `solution` as day `day`, turning a panic into an error.
+pub fn solve_with<S: Solution>(
+    solution: &S,
+    day: u8,
+    part: Part,
+    input: &str,
+) -> Result<Solved, SolveError> {
+    let _span = info_span!("day", day).entered();
+    match panics::catch(|| common::solve_timed(solution, input, part)) {
+        Ok(solved) => solved.map_err(SolveError::from),
+        Err(message) => Err(SolveError::Panic(message)),
+    }
+}
+
+/// The version of a day's solution, or `None` if there is no solution for that day.
+pub fn version(day: u8) -> Option<u32> {
+    fn version_of<S: Solution>(_: &S) -> u32 {
+        S::VERSION
+    }
+
+    with_solution!(day, |solution| version_of(solution))
+}
+
+/// Times parsing and both parts of a day over `runs` runs.
+/// Returns `None` if there is no solution for that day.
+pub fn bench(day: u8, input: &str, runs: u32) -> Option<Result<Timings, SolveError>> {
+    with_solution!(day, |solution| {
+        let _span = info_span!("bench", day, runs).entered();
+        match panics::catch(|| bench::run(solution, input, runs)) {
+            Ok(timings) => timings.map_err(SolveError::from),
+            Err(message) => Err(SolveError::Panic(message)),
+        }
+    })
+}
+
+/// Which parts to run, parsed from `1`, `2` or `both`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn iter(&self) -> impl Iterator<Item = Part> {
+        let parts: &[Part] = match self {
+            Parts::One => &[Part::One],
+            Parts::Two => &[Part::Two],
+            Parts::Both => &[Part::One, Part::Two],
+        };
+        parts.iter().copied()
+    }
+}
+
+impl FromStr for Parts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Parts, String> {
+        match s {
+            "1" => Ok(Parts::One),
+            "2" => Ok(Parts::Two),
+            "both" => Ok(Parts::Both),
+            _ => Err(format!("invalid part '{}', expected 1, 2 or both", s)),
+        }
+    }
+}
+
+/// Days to run, parsed from a single day (`12`), a range (`3-7`) or `all`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Days(RangeInclusive<u8>);
+
+impl Days {
+    pub fn iter(&self) -> RangeInclusive<u8> {
+        self.0.clone()
+    }
+
+    pub fn is_single(&self) -> bool {
+        self.0.start() == self.0.end()
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Days, String> {
+        let parse_day = |day: &str| match day.trim().parse::<u8>() {
+            Ok(d) if (FIRST_DAY..=LAST_DAY).contains(&d) => Ok(d),
+            _ => Err(format!(
+                "invalid day '{}', expected a number from {} to {}",
+                day, FIRST_DAY, LAST_DAY
+            )),
+        };
+
+        if s == "all" {
+            return Ok(Days(FIRST_DAY..=LAST_DAY));
+        }
+
+        match s.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("invalid day range '{}'", s));
+                }
+                Ok(Days(start..=end))
+            }
+            None => {
+                let day = parse_day(s)?;
+                Ok(Days(day..=day))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_days_test() {
+        assert_eq!("12".parse(), Ok(Days(12..=12)));
+        assert_eq!("3-7".parse(), Ok(Days(3..=7)));
+        assert_eq!("all".parse(), Ok(Days(1..=15)));
+        assert!("7-3".parse::<Days>().is_err());
+        assert!("0".parse::<Days>().is_err());
+        assert!("16".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn parse_parts_test() {
+        assert_eq!("both".parse::<Parts>().unwrap().iter().count(), 2);
+        assert_eq!("2".parse::<Parts>().unwrap().iter().next(), Some(Part::Two));
+        assert!("3".parse::<Parts>().is_err());
+    }
+}
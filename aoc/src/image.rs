@@ -0,0 +1,73 @@
+use common::{ParseError, Part, Solution};
+use grid::Grid;
+use visual::Rgb;
+
+/// Days with an image.
+pub const DAYS: [u8; 6] = [8, 9, 10, 12, 14, 15];
+
+// Pixels along the longer side of the day 15 coverage map, before scaling
+const COVERAGE_SIZE: usize = 400;
+
+/// Draws a day's result as an image. Part 2 uses the ten knot rope on day 9,
+/// any lowest square as the start on day 12 and the cave with a floor on
+/// day 14. The other days draw the same image for both parts.
+/// Returns `None` if there is no image for that day.
+pub fn render(day: u8, part: Part, input: &str) -> Option<Result<Grid<Rgb>, ParseError>> {
+    let part_2 = part == Part::Two;
+    let image = match day {
+        8 => day08::Day08
+            .parse(input)
+            .map(|heights| day08::scenic_image(&heights)),
+        9 => day09::Day09
+            .parse(input)
+            .map(|moves| day09::visited_image(&moves, if part_2 { 9 } else { 1 })),
+        10 => day10::Day10
+            .parse(input)
+            .map(|program| day10::screen_image(&program)),
+        12 => day12::Day12
+            .parse(input)
+            .map(|squares| day12::route_image(&squares, part_2)),
+        14 => day14::Day14
+            .parse(input)
+            .map(|paths| day14::sand_image(&paths, part_2)),
+        15 => day15::Day15::default()
+            .parse(input)
+            .map(|sensors| day15::coverage_image(&sensors, COVERAGE_SIZE)),
+        _ => return None,
+    };
+    Some(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        for day in DAYS {
+            let path = format!(
+                "{}/../day{:02}/example.txt",
+                env!("CARGO_MANIFEST_DIR"),
+                day
+            );
+            let input = std::fs::read_to_string(path).unwrap();
+            let image = render(day, Part::One, &input).unwrap().unwrap();
+            assert!(image.width() > 0 && image.height() > 0, "day {}", day);
+        }
+        assert!(render(1, Part::One, "1\n").is_none());
+    }
+
+    #[test]
+    fn screen_test() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../day10/example.txt");
+        let input = std::fs::read_to_string(path).unwrap();
+        let image = render(10, Part::One, &input).unwrap().unwrap();
+
+        let first_row: String = image
+            .row(0)
+            .iter()
+            .map(|&pixel| if pixel == Rgb::BLACK { '.' } else { '#' })
+            .collect();
+        assert_eq!(first_row, "##..##..##..##..##..##..##..##..##..##..");
+    }
+}
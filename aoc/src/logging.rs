@@ -0,0 +1,42 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::level_filters::LevelFilter;
+
+/// Most detailed level logged for a number of `-v` flags: warnings only by
+/// default, then parse and solve times, then debugging output, then everything.
+pub fn level(verbose: u8) -> LevelFilter {
+    match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Sends the log to `log_file`, or to stderr so it never mixes with answers.
+pub fn init(verbose: u8, log_file: Option<&Path>) -> io::Result<()> {
+    let subscriber = tracing_subscriber::fmt().with_max_level(level(verbose));
+
+    match log_file {
+        Some(path) => subscriber
+            .with_ansi(false)
+            .with_writer(Mutex::new(File::create(path)?))
+            .init(),
+        None => subscriber.with_writer(io::stderr).init(),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_test() {
+        assert_eq!(level(0), LevelFilter::WARN);
+        assert_eq!(level(2), LevelFilter::DEBUG);
+        assert_eq!(level(7), LevelFilter::TRACE);
+    }
+}
@@ -0,0 +1,745 @@
+mod animate;
+mod bench;
+mod cache;
+mod days;
+mod image;
+mod logging;
+mod panics;
+mod server;
+mod summary;
+
+use bench::Timings;
+use cache::Cache;
+use clap::{Parser, Subcommand, ValueEnum};
+use common::{Answer, Input, ParseError, Part, Solution};
+use days::{Days, Parts, SolveError};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Instant;
+use visual::Terminal;
+
+/// Runs the Advent of Code 2022 solutions from a single binary.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log to stderr: `-v` for parse and solve times, `-vv` for debugging
+    /// output, `-vvv` for everything
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Write the log to this file instead of stderr
+    #[arg(long, global = true)]
+    log_file: Option<PathBuf>,
+
+    /// Directory answers are cached in
+    #[arg(long, global = true, default_value = ".aoc-cache")]
+    cache_dir: PathBuf,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, a range of days or all of them
+    Run {
+        /// Day to run: a single day (`12`), a range (`3-7`) or `all`
+        #[arg(long, default_value = "all")]
+        day: Days,
+
+        /// Part to run: `1`, `2` or `both`
+        #[arg(long, default_value = "both")]
+        part: Parts,
+
+        /// Puzzle input file, or `-` for stdin. Only allowed when running a single day
+        #[arg(long)]
+        input: Option<Input>,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Solve every part even if its answer is cached, and don't cache new answers
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Time parsing and both parts of one day, a range of days or all of them
+    Bench {
+        /// Day to time: a single day (`12`), a range (`3-7`) or `all`
+        #[arg(long, default_value = "all")]
+        day: Days,
+
+        /// Number of times to run each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+
+        /// Report format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+    },
+    /// Run every part of every day in parallel and print a summary table
+    Summary {
+        /// Day to run: a single day (`12`), a range (`3-7`) or `all`
+        #[arg(long, default_value = "all")]
+        day: Days,
+
+        /// Number of worker threads. Defaults to the number of CPUs
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        threads: Option<u32>,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+
+        /// Solve every part even if its answer is cached, and don't cache new answers
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Animate day 9, 10 or 14 in the terminal. Space pauses, `n` steps while
+    /// paused and `q` quits
+    Animate {
+        /// Day to animate
+        #[arg(long)]
+        day: u8,
+
+        /// Part to animate: `1` or `2`
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Input>,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+
+        /// Frames a second, or 0 for as fast as possible
+        #[arg(long, default_value_t = 30)]
+        fps: u32,
+    },
+    /// Draw a day's result as a PNG or PPM image: day 8, 9, 10, 12, 14 or 15
+    Image {
+        /// Day to draw
+        #[arg(long)]
+        day: u8,
+
+        /// Part to draw: `1` or `2`
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Image file to write, ending in `.png` or `.ppm`
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Size in pixels of each cell of the image
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+
+        /// Puzzle input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Input>,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+    },
+    /// Answer `POST /day/{n}/part/{p}` requests on localhost, with the puzzle
+    /// input as the body and the answer and timings as JSON
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+
+        /// Number of requests handled at once. Defaults to the number of CPUs
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        threads: Option<u32>,
+    },
+    /// Work with the day 1 calorie list
+    Calories {
+        #[command(subcommand)]
+        command: CaloriesCommand,
+    },
+    /// Score a day 2 strategy guide written with other symbols, or for
+    /// another game
+    Guide {
+        /// TOML or JSON file saying what the symbols of the guide stand for.
+        /// Defaults to the puzzle's A, B, C and X, Y, Z
+        #[arg(long)]
+        cipher: Option<PathBuf>,
+
+        /// Game the guide is for
+        #[arg(long, value_enum, default_value_t = Game::Rps)]
+        game: Game,
+
+        /// Strategy guide file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Input>,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+    },
+    /// Remove cached answers
+    ClearCache {
+        /// Day to remove answers for: a single day (`12`), a range (`3-7`) or `all`
+        #[arg(long, default_value = "all")]
+        day: Days,
+    },
+    /// Print a random puzzle input for a day
+    Gen {
+        /// Day to generate an input for
+        #[arg(long, value_parser = clap::value_parser!(u8).range(days::FIRST_DAY as i64..=days::LAST_DAY as i64))]
+        day: u8,
+
+        /// How big the input is: the number of elves, moves, monkeys, the side of a grid and so on
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed for the random generator. The same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum CaloriesCommand {
+    /// Stream the calorie list and print the elves carrying the most. Reads a
+    /// line at a time, so inputs of any size can be piped in
+    Top {
+        /// Number of elves to print
+        #[arg(long, default_value_t = 3)]
+        count: usize,
+
+        /// Calorie list file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Input>,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+    },
+    /// Print statistics about the elves: mean, median and percentiles of
+    /// their totals, how many items they carry, a histogram of the totals and
+    /// elves carrying the same items
+    Report {
+        /// Calorie list file, or `-` for stdin
+        #[arg(long)]
+        input: Option<Input>,
+
+        /// Directory holding the `dayNN/input.txt` files
+        #[arg(long, default_value = ".")]
+        input_dir: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Game {
+    /// Rock, paper, scissors
+    Rps,
+    /// Rock, paper, scissors, lizard, Spock
+    Rpsls,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Plain text for reading
+    Text,
+    /// JSON with times in nanoseconds
+    Json,
+}
+
+#[derive(Serialize)]
+struct PartResult {
+    day: u8,
+    part: Part,
+    answer: Answer,
+    parse_ns: u128,
+    solve_ns: u128,
+    /// Whether the answer came from the cache, in which case the times are
+    /// from when it was first solved
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    cached: bool,
+}
+
+#[derive(Serialize)]
+struct BenchReport {
+    runs: u32,
+    days: Vec<DayTimings>,
+}
+
+#[derive(Serialize)]
+struct DayTimings {
+    day: u8,
+    #[serde(flatten)]
+    timings: Timings,
+}
+
+fn day_input(day: u8, input: &Option<Input>, input_dir: &Path) -> Input {
+    match input {
+        Some(input) => input.clone(),
+        None => Input::File(input_dir.join(format!("day{:02}", day)).join("input.txt")),
+    }
+}
+
+fn run(
+    days: Days,
+    parts: Parts,
+    input: Option<Input>,
+    input_dir: PathBuf,
+    format: Format,
+    cache: Option<Cache>,
+) -> ExitCode {
+    if input.is_some() && !days.is_single() {
+        eprintln!("--input can only be used when running a single day");
+        return ExitCode::FAILURE;
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    let mut results = vec![];
+
+    for day in days.iter() {
+        let source = day_input(day, &input, &input_dir);
+        let puzzle_input = match source.read() {
+            Ok(puzzle_input) => puzzle_input,
+            Err(e) => {
+                eprintln!("Day {}: could not read {}: {}", day, source, e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for part in parts.iter() {
+            match cache::solve(cache.as_ref(), day, part, &puzzle_input) {
+                Some(Err(SolveError::Parse(e))) => {
+                    eprintln!("{}", e);
+                    status = ExitCode::FAILURE;
+                    break;
+                }
+                Some(Err(e)) => {
+                    eprintln!("Day {}, part {}: {}", day, part, e);
+                    status = ExitCode::FAILURE;
+                }
+                Some(Ok((solved, cached))) => match format {
+                    Format::Text => {
+                        let answer = solved.answer.to_string();
+                        if answer.contains('\n') {
+                            println!("Day {}, part {}:\n{}", day, part, answer);
+                        } else {
+                            println!("Day {}, part {}: {}", day, part, answer);
+                        }
+                    }
+                    Format::Json => results.push(PartResult {
+                        day,
+                        part,
+                        answer: solved.answer,
+                        parse_ns: solved.parse_time.as_nanos(),
+                        solve_ns: solved.solve_time.as_nanos(),
+                        cached,
+                    }),
+                },
+                None => {
+                    eprintln!("Day {}: no solution", day);
+                    status = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    if let Format::Json = format {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+
+    status
+}
+
+fn bench(days: Days, runs: u32, format: Format, input_dir: PathBuf) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    let mut report = BenchReport { runs, days: vec![] };
+
+    for day in days.iter() {
+        let source = day_input(day, &None, &input_dir);
+        let puzzle_input = match source.read() {
+            Ok(puzzle_input) => puzzle_input,
+            Err(e) => {
+                eprintln!("Day {}: could not read {}: {}", day, source, e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        match days::bench(day, &puzzle_input, runs) {
+            Some(Ok(timings)) => report.days.push(DayTimings { day, timings }),
+            Some(Err(SolveError::Parse(e))) => {
+                eprintln!("{}", e);
+                status = ExitCode::FAILURE;
+            }
+            Some(Err(e)) => {
+                eprintln!("Day {}: {}", day, e);
+                status = ExitCode::FAILURE;
+            }
+            None => {
+                eprintln!("Day {}: no solution", day);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match format {
+        Format::Text => {
+            println!(
+                "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+                "Day", "Step", "Min", "Median", "Max"
+            );
+            for DayTimings { day, timings } in &report.days {
+                let steps = [
+                    ("parse", timings.parse),
+                    ("part 1", timings.part_1),
+                    ("part 2", timings.part_2),
+                ];
+                for (step, stats) in steps {
+                    println!(
+                        "{:>3}  {:<6}  {:>12.2?}  {:>12.2?}  {:>12.2?}",
+                        day, step, stats.min, stats.median, stats.max
+                    );
+                }
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&report).unwrap()),
+    }
+
+    status
+}
+
+fn summary(days: Days, threads: Option<u32>, input_dir: PathBuf, cache: Option<Cache>) -> ExitCode {
+    let start = Instant::now();
+    let inputs: Vec<Result<String, String>> = days
+        .iter()
+        .map(|day| {
+            let source = day_input(day, &None, &input_dir);
+            source
+                .read()
+                .map_err(|e| format!("could not read {}: {}", source, e))
+        })
+        .collect();
+
+    let outcomes = summary::run(&days, &inputs, worker_count(threads), |day, part, input| {
+        cache::solve(cache.as_ref(), day, part, input)
+    });
+    print!("{}", summary::table(&outcomes, start.elapsed()));
+
+    if outcomes.iter().all(|outcome| outcome.result.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn animate(day: u8, part: Part, input: Option<Input>, input_dir: PathBuf, fps: u32) -> ExitCode {
+    if !animate::DAYS.contains(&day) {
+        eprintln!("Day {}: no animation, try day 9, 10 or 14", day);
+        return ExitCode::FAILURE;
+    }
+
+    let source = day_input(day, &input, &input_dir);
+    let puzzle_input = match source.read() {
+        Ok(puzzle_input) => puzzle_input,
+        Err(e) => {
+            eprintln!("Day {}: could not read {}: {}", day, source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut terminal = match Terminal::new(fps) {
+        Ok(terminal) => terminal,
+        Err(e) => {
+            eprintln!("could not set up the terminal: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let animated = animate::animate(day, part, &puzzle_input, &mut terminal);
+    if let Some(Ok(())) = animated {
+        let _ = terminal.finish();
+    }
+    drop(terminal);
+
+    match animated {
+        Some(Ok(())) => ExitCode::SUCCESS,
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+        None => unreachable!("day {} has no animation", day),
+    }
+}
+
+fn image(
+    day: u8,
+    part: Part,
+    output: PathBuf,
+    scale: u32,
+    input: Option<Input>,
+    input_dir: PathBuf,
+) -> ExitCode {
+    if !image::DAYS.contains(&day) {
+        eprintln!("Day {}: no image, try day 8, 9, 10, 12, 14 or 15", day);
+        return ExitCode::FAILURE;
+    }
+
+    let source = day_input(day, &input, &input_dir);
+    let puzzle_input = match source.read() {
+        Ok(puzzle_input) => puzzle_input,
+        Err(e) => {
+            eprintln!("Day {}: could not read {}: {}", day, source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let picture = match image::render(day, part, &puzzle_input) {
+        Some(Ok(picture)) => picture,
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        None => unreachable!("day {} has no image", day),
+    };
+
+    match visual::image::save(&picture, &output, scale as usize) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("could not write {}: {}", output.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn top_elves(count: usize, input: Option<Input>, input_dir: PathBuf) -> ExitCode {
+    let source = day_input(1, &input, &input_dir);
+    let top = match source
+        .open()
+        .and_then(|reader| day01::stream_top_n(reader, count))
+    {
+        Ok(top) => top,
+        Err(e) => {
+            match e.get_ref().and_then(|e| e.downcast_ref::<ParseError>()) {
+                Some(e) => eprintln!("{}", e),
+                None => eprintln!("could not read {}: {}", source, e),
+            }
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for (index, total) in &top {
+        println!("Elf {}: {}", index + 1, total);
+    }
+    let sum: u64 = top.iter().map(|&(_, total)| total).sum();
+    println!("Total: {}", sum);
+    ExitCode::SUCCESS
+}
+
+fn calorie_report(input: Option<Input>, input_dir: PathBuf) -> ExitCode {
+    let source = day_input(1, &input, &input_dir);
+    let puzzle_input = match source.read() {
+        Ok(puzzle_input) => puzzle_input,
+        Err(e) => {
+            eprintln!("could not read {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let elves = match day01::Day01.parse(&puzzle_input) {
+        Ok(elves) => elves,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match day01::Report::new(&elves) {
+        Some(report) => {
+            print!("{}", report);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("{} has no elves", source);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn guide(
+    cipher: Option<PathBuf>,
+    game: Game,
+    input: Option<Input>,
+    input_dir: PathBuf,
+) -> ExitCode {
+    let rules = match game {
+        Game::Rps => day02::Rules::standard(),
+        Game::Rpsls => day02::Rules::rpsls(),
+    };
+    let cipher = match cipher {
+        Some(path) => day02::Cipher::load(&path)
+            .map_err(|e| format!("could not load {}: {}", path.display(), e)),
+        None => Ok(day02::Cipher::standard()),
+    };
+    let cipher = match cipher.and_then(|cipher| cipher.check(&rules).map(|()| cipher)) {
+        Ok(cipher) => cipher,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = day_input(2, &input, &input_dir);
+    let puzzle_input = match source.read() {
+        Ok(puzzle_input) => puzzle_input,
+        Err(e) => {
+            eprintln!("could not read {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let rounds = match day02::parse_guide(&puzzle_input, &rules, &cipher) {
+        Ok(rounds) => rounds,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Part 1: {}", day02::score_shapes(&rules, &rounds));
+    match day02::score_outcomes(&rules, &rounds) {
+        Some(score) => println!("Part 2: {}", score),
+        None => println!("Part 2: some outcome can't be had"),
+    }
+    ExitCode::SUCCESS
+}
+
+fn clear_cache(days: Days, cache: Cache) -> ExitCode {
+    match cache.clear(days.iter()) {
+        Ok(removed) => {
+            println!(
+                "Removed {} cached answers from {}",
+                removed,
+                cache.dir().display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("could not clear {}: {}", cache.dir().display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(port: u16, threads: Option<u32>) -> ExitCode {
+    let addr = format!("127.0.0.1:{}", port);
+    eprintln!("Listening on http://{}", addr);
+
+    match server::serve(&addr, worker_count(threads)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("could not listen on {}: {}", addr, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// `threads` if given, otherwise the number of CPUs
+fn worker_count(threads: Option<u32>) -> usize {
+    match threads {
+        Some(threads) => threads as usize,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    }
+}
+
+fn to_part(part: u8) -> Part {
+    if part == 1 {
+        Part::One
+    } else {
+        Part::Two
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(e) = logging::init(cli.verbose, cli.log_file.as_deref()) {
+        eprintln!("could not open the log file: {}", e);
+        return ExitCode::FAILURE;
+    }
+    panics::install_hook();
+    let cache_dir = cli.cache_dir;
+    let use_cache = |no_cache: bool| (!no_cache).then(|| Cache::new(&cache_dir));
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            input_dir,
+            format,
+            no_cache,
+        } => run(day, part, input, input_dir, format, use_cache(no_cache)),
+        Command::Bench {
+            day,
+            runs,
+            format,
+            input_dir,
+        } => bench(day, runs, format, input_dir),
+        Command::Summary {
+            day,
+            threads,
+            input_dir,
+            no_cache,
+        } => summary(day, threads, input_dir, use_cache(no_cache)),
+        Command::Animate {
+            day,
+            part,
+            input,
+            input_dir,
+            fps,
+        } => animate(day, to_part(part), input, input_dir, fps),
+        Command::Image {
+            day,
+            part,
+            output,
+            scale,
+            input,
+            input_dir,
+        } => image(day, to_part(part), output, scale, input, input_dir),
+        Command::Calories { command } => match command {
+            CaloriesCommand::Top {
+                count,
+                input,
+                input_dir,
+            } => top_elves(count, input, input_dir),
+            CaloriesCommand::Report { input, input_dir } => calorie_report(input, input_dir),
+        },
+        Command::Guide {
+            cipher,
+            game,
+            input,
+            input_dir,
+        } => guide(cipher, game, input, input_dir),
+        Command::ClearCache { day } => clear_cache(day, Cache::new(&cache_dir)),
+        Command::Serve { port, threads } => serve(port, threads),
+        Command::Gen { day, size, seed } => match generate::generate(day, size, seed) {
+            Some(input) => {
+                print!("{}", input);
+                ExitCode::SUCCESS
+            }
+            None => {
+                eprintln!("Day {}: no generator", day);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
@@ -0,0 +1,91 @@
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    // Set while `catch` runs, so the hook keeps the panic instead of printing it
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that stays quiet for panics caught by `catch` and
+/// records their message and location. Other panics are printed as usual.
+/// Installing it more than once has no effect.
+pub fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| hook(&default_hook, info)));
+    });
+}
+
+type Hook = dyn Fn(&panic::PanicHookInfo) + Sync + Send;
+
+fn hook(default_hook: &Hook, info: &panic::PanicHookInfo) {
+    if !CATCHING.with(Cell::get) {
+        default_hook(info);
+        return;
+    }
+
+    let payload = info.payload();
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    };
+    let message = match info.location() {
+        Some(location) => format!("{} at {}:{}", message, location.file(), location.line()),
+        None => message,
+    };
+    CAUGHT.with(|caught| *caught.borrow_mut() = Some(message));
+}
+
+/// Runs `f`, turning a panic into an error with its message.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    CATCHING.with(|catching| catching.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(false));
+
+    result.map_err(|_| {
+        CAUGHT
+            .with(|caught| caught.borrow_mut().take())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// A solution that panics in both parts, for testing how panics are reported.
+#[cfg(test)]
+pub struct Panicking;
+
+#[cfg(test)]
+impl common::Solution for Panicking {
+    type Input = ();
+
+    fn parse(&self, _input: &str) -> Result<(), common::ParseError> {
+        Ok(())
+    }
+
+    fn part_1(&self, _input: &()) -> common::Answer {
+        panic!("no answer")
+    }
+
+    fn part_2(&self, _input: &()) -> common::Answer {
+        panic!("no answer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_test() {
+        install_hook();
+        assert_eq!(catch(|| 1), Ok(1));
+
+        let err = catch(|| -> i32 { panic!("not {}", "implemented") }).unwrap_err();
+        assert!(err.starts_with("not implemented at "), "{}", err);
+    }
+}
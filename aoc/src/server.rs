@@ -0,0 +1,188 @@
+use crate::days::{self, SolveError};
+use crate::PartResult;
+use common::{Part, Solved};
+use serde::Serialize;
+use std::error::Error;
+use std::thread;
+use tiny_http::{Header, Method, Request, Response, Server};
+use tracing::{info, warn};
+
+/// A status code and JSON body, ready to send.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
+
+fn reply(status: u16, body: &impl Serialize) -> Reply {
+    Reply {
+        status,
+        body: serde_json::to_string(body).unwrap(),
+    }
+}
+
+fn error(status: u16, message: impl Into<String>) -> Reply {
+    let body = ErrorBody {
+        error: message.into(),
+        line: None,
+        column: None,
+    };
+    reply(status, &body)
+}
+
+/// Answers a request. `POST /day/{n}/part/{p}` solves part `p` of day `n`
+/// with the request body as the puzzle input.
+pub fn handle(method: &Method, url: &str, body: &str) -> Reply {
+    handle_with(method, url, body, days::solve)
+}
+
+// `handle` with the days solved by `solve` instead of `days::solve`
+fn handle_with(
+    method: &Method,
+    url: &str,
+    body: &str,
+    solve: impl Fn(u8, Part, &str) -> Option<Result<Solved, SolveError>>,
+) -> Reply {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (day, part) = match segments[..] {
+        ["day", day, "part", part] => (day, part),
+        _ => return error(404, format!("no route for '{}'", path)),
+    };
+
+    if *method != Method::Post {
+        return error(405, "use POST with the puzzle input as the body");
+    }
+    let day: u8 = match day.parse() {
+        Ok(day) => day,
+        Err(_) => return error(400, format!("invalid day '{}'", day)),
+    };
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return error(400, format!("invalid part '{}', expected 1 or 2", part)),
+    };
+
+    match solve(day, part, body) {
+        Some(Ok(solved)) => reply(
+            200,
+            &PartResult {
+                day,
+                part,
+                answer: solved.answer,
+                parse_ns: solved.parse_time.as_nanos(),
+                solve_ns: solved.solve_time.as_nanos(),
+                cached: false,
+            },
+        ),
+        Some(Err(SolveError::Parse(e))) => reply(
+            422,
+            &ErrorBody {
+                error: e.to_string(),
+                line: Some(e.line),
+                column: Some(e.column),
+            },
+        ),
+        Some(Err(e)) => error(500, e.to_string()),
+        None => error(404, format!("no solution for day {}", day)),
+    }
+}
+
+fn respond(mut request: Request) {
+    let mut body = String::new();
+    let reply = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => handle(request.method(), request.url(), &body),
+        Err(_) => error(400, "the puzzle input must be UTF-8"),
+    };
+    info!(method = %request.method(), url = request.url(), status = reply.status, "request");
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(content_type);
+    if let Err(e) = request.respond(response) {
+        warn!(error = %e, "could not send the response");
+    }
+}
+
+/// Serves requests on `addr` with `threads` workers, until the process is killed.
+pub fn serve(addr: &str, threads: usize) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let server = Server::http(addr)?;
+
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| loop {
+                match server.recv() {
+                    Ok(request) => respond(request),
+                    Err(e) => {
+                        warn!(error = %e, "stopped accepting requests");
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::panics::Panicking;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn solve_test() {
+        let reply = handle(&Method::Post, "/day/1/part/1", EXAMPLE);
+        assert_eq!(reply.status, 200);
+
+        let body: serde_json::Value = serde_json::from_str(&reply.body).unwrap();
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["part"], 1);
+        assert_eq!(body["answer"], 24000);
+        assert!(body["solve_ns"].is_u64());
+    }
+
+    #[test]
+    fn error_test() {
+        crate::panics::install_hook();
+
+        let status = |method: Method, url: &str, body: &str| handle(&method, url, body).status;
+        assert_eq!(status(Method::Get, "/day/1/part/1", EXAMPLE), 405);
+        assert_eq!(status(Method::Post, "/days/1", EXAMPLE), 404);
+        assert_eq!(status(Method::Post, "/day/99/part/1", EXAMPLE), 404);
+        assert_eq!(status(Method::Post, "/day/x/part/1", EXAMPLE), 400);
+        assert_eq!(status(Method::Post, "/day/1/part/3", EXAMPLE), 400);
+
+        let reply = handle(&Method::Post, "/day/1/part/1", "100\nabc\n");
+        assert_eq!(reply.status, 422);
+        let body: serde_json::Value = serde_json::from_str(&reply.body).unwrap();
+        assert_eq!(
+            (body["line"].as_u64(), body["column"].as_u64()),
+            (Some(2), Some(1))
+        );
+    }
+
+    #[test]
+    fn panic_test() {
+        crate::panics::install_hook();
+
+        let solve = |day, part, input: &str| Some(days::solve_with(&Panicking, day, part, input));
+        let reply = handle_with(&Method::Post, "/day/1/part/2", EXAMPLE, solve);
+        assert_eq!(reply.status, 500);
+
+        let body: serde_json::Value = serde_json::from_str(&reply.body).unwrap();
+        let error = body["error"].as_str().unwrap();
+        assert!(error.starts_with("panicked: no answer at "), "{}", error);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::days::{Days, SolveError};
+use common::{Answer, Part, Solved};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The result of running one part of one day.
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub result: Result<Solved, String>,
+    /// Whether the answer came from the cache
+    pub cached: bool,
+    /// Wall time of the whole run, parsing included
+    pub elapsed: Duration,
+}
+
+struct Job<'a> {
+    day: u8,
+    part: Part,
+    input: Result<&'a str, &'a str>,
+}
+
+/// Solves one part of a day, like `cache::solve`.
+pub trait Solver: Fn(u8, Part, &str) -> Option<Result<(Solved, bool), SolveError>> + Sync {}
+
+impl<F> Solver for F where F: Fn(u8, Part, &str) -> Option<Result<(Solved, bool), SolveError>> + Sync
+{}
+
+fn run_job(job: &Job, solve: &impl Solver) -> Outcome {
+    let start = Instant::now();
+    let result = match job.input {
+        Ok(input) => match solve(job.day, job.part, input) {
+            Some(result) => result.map_err(|e| e.to_string()),
+            None => Err("no solution".to_string()),
+        },
+        Err(e) => Err(e.to_string()),
+    };
+
+    Outcome {
+        day: job.day,
+        part: job.part,
+        cached: matches!(result, Ok((_, true))),
+        result: result.map(|(solved, _)| solved),
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Runs both parts of every day on `threads` worker threads. `inputs` holds
+/// each day's puzzle input, or why it couldn't be read, and `solve` solves
+/// a part of a day. The outcomes are sorted by elapsed time, slowest first.
+pub fn run(
+    days: &Days,
+    inputs: &[Result<String, String>],
+    threads: usize,
+    solve: impl Solver,
+) -> Vec<Outcome> {
+    let jobs: Vec<Job> = days
+        .iter()
+        .zip(inputs)
+        .flat_map(|(day, input)| {
+            [Part::One, Part::Two].map(|part| Job {
+                day,
+                part,
+                input: input.as_deref().map_err(String::as_str),
+            })
+        })
+        .collect();
+
+    // Workers take the next job until there are none left
+    let next = AtomicUsize::new(0);
+    let mut outcomes: Vec<Outcome> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.clamp(1, jobs.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut outcomes = vec![];
+                    while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        outcomes.push(run_job(job, &solve));
+                    }
+                    outcomes
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    outcomes.sort_by(|a, b| {
+        b.elapsed
+            .cmp(&a.elapsed)
+            .then((a.day, a.part).cmp(&(b.day, b.part)))
+    });
+    outcomes
+}
+
+fn answer_cell(answer: &Answer) -> String {
+    let answer = answer.to_string();
+    match answer.lines().count() {
+        0 | 1 => answer,
+        n => format!("<{} lines>", n),
+    }
+}
+
+/// Formats the outcomes as a table with a line of totals at the bottom.
+pub fn table(outcomes: &[Outcome], wall_time: Duration) -> String {
+    let mut table = format!(
+        "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}  {:>12}\n",
+        "Day", "Part", "Answer", "Parse", "Solve", "Total"
+    );
+
+    for outcome in outcomes {
+        let line = match &outcome.result {
+            Ok(solved) => format!(
+                "{:>3}  {:>4}  {:<20}  {:>12.2?}  {:>12.2?}  {:>12.2?}",
+                outcome.day,
+                outcome.part,
+                answer_cell(&solved.answer),
+                solved.parse_time,
+                solved.solve_time,
+                outcome.elapsed
+            ),
+            Err(e) => format!(
+                "{:>3}  {:>4}  error: {}",
+                outcome.day,
+                outcome.part,
+                e.lines().next().unwrap_or_default()
+            ),
+        };
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+
+    let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+    let cached = match outcomes.iter().filter(|o| o.cached).count() {
+        0 => String::new(),
+        n => format!(" ({} cached)", n),
+    };
+    table.push_str(&format!(
+        "\n{} solved{}, {} failed in {:.2?}\n",
+        outcomes.len() - failed,
+        cached,
+        failed,
+        wall_time
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::panics::Panicking;
+
+    // Solves the real days, except that day 2 always panics
+    fn solve(day: u8, part: Part, input: &str) -> Option<Result<(Solved, bool), SolveError>> {
+        let solved = match day {
+            2 => Some(days::solve_with(&Panicking, day, part, input)),
+            _ => days::solve(day, part, input),
+        };
+        solved.map(|result| result.map(|solved| (solved, false)))
+    }
+
+    #[test]
+    fn run_test() {
+        crate::panics::install_hook();
+
+        let days: Days = "1-2".parse().unwrap();
+        let inputs = [
+            Ok("1\n2\n\n3\n\n4\n".to_string()),
+            Err("could not read input".to_string()),
+        ];
+        let outcomes = run(&days, &inputs, 3, solve);
+        assert_eq!(outcomes.len(), 4);
+
+        let mut answers: Vec<_> = outcomes
+            .iter()
+            .map(|o| {
+                (
+                    o.day,
+                    o.part,
+                    o.result
+                        .as_ref()
+                        .map(|s| s.answer.to_string())
+                        .map_err(String::clone),
+                )
+            })
+            .collect();
+        answers.sort();
+        assert_eq!(
+            answers,
+            vec![
+                (1, Part::One, Ok("4".to_string())),
+                (1, Part::Two, Ok("10".to_string())),
+                (2, Part::One, Err("could not read input".to_string())),
+                (2, Part::Two, Err("could not read input".to_string())),
+            ]
+        );
+
+        let table = table(&outcomes, Duration::from_millis(1));
+        assert!(
+            table.ends_with("2 solved, 2 failed in 1.00ms\n"),
+            "{}",
+            table
+        );
+    }
+
+    #[test]
+    fn panic_test() {
+        crate::panics::install_hook();
+
+        let days: Days = "1-2".parse().unwrap();
+        let inputs = [Ok("1\n".to_string()), Ok("1\n".to_string())];
+        let outcomes = run(&days, &inputs, 2, solve);
+
+        let failed: Vec<_> = outcomes.iter().filter(|o| o.result.is_err()).collect();
+        assert_eq!(failed.len(), 2);
+        for outcome in failed {
+            assert_eq!(outcome.day, 2);
+            let e = outcome.result.as_ref().unwrap_err();
+            assert!(e.starts_with("panicked: no answer at "), "{}", e);
+        }
+    }
+}
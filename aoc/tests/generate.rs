@@ -0,0 +1,75 @@
+//! Every generated input parses and solves without panicking.
+
+use common::Solution;
+
+fn solves<S: Solution>(solution: &S, day: u8) {
+    solves_at(solution, day, 30);
+}
+
+fn solves_at<S: Solution>(solution: &S, day: u8, size: usize) {
+    for seed in 0..10 {
+        let input = generate::generate(day, size, seed).unwrap();
+        let parsed = solution
+            .parse(&input)
+            .unwrap_or_else(|e| panic!("seed {}: {}\n{}", seed, e, input));
+        solution.part_1(&parsed);
+        solution.part_2(&parsed);
+    }
+}
+
+#[test]
+fn generated_inputs_solve() {
+    solves(&day01::Day01, 1);
+    solves(&day02::Day02, 2);
+    solves(&day03::Day03, 3);
+    solves(&day04::Day04, 4);
+    solves(&day05::Day05, 5);
+    solves(&day06::Day06, 6);
+    solves(&day07::Day07, 7);
+    solves(&day08::Day08, 8);
+    solves(&day09::Day09, 9);
+    solves(&day10::Day10, 10);
+    solves(&day11::Day11, 11);
+    solves(&day12::Day12, 12);
+    solves(&day13::Day13, 13);
+    solves(&day14::Day14, 14);
+    solves(&day15::Day15::default(), 15);
+}
+
+#[test]
+fn smallest_generated_inputs_solve() {
+    solves_at(&day01::Day01, 1, 0);
+    solves_at(&day02::Day02, 2, 0);
+    solves_at(&day03::Day03, 3, 0);
+    solves_at(&day04::Day04, 4, 0);
+    solves_at(&day05::Day05, 5, 0);
+    solves_at(&day06::Day06, 6, 0);
+    solves_at(&day07::Day07, 7, 0);
+    solves_at(&day08::Day08, 8, 0);
+    solves_at(&day09::Day09, 9, 0);
+    solves_at(&day10::Day10, 10, 0);
+    solves_at(&day11::Day11, 11, 0);
+    solves_at(&day12::Day12, 12, 0);
+    solves_at(&day13::Day13, 13, 0);
+    solves_at(&day14::Day14, 14, 0);
+    solves_at(&day15::Day15::default(), 15, 0);
+}
+
+#[test]
+fn large_generated_inputs_solve() {
+    // Enough directories that their files would fill the disk if file
+    // sizes didn't shrink with the number of directories
+    solves_at(&day07::Day07, 7, 3000);
+}
+
+#[test]
+fn generated_stacks_keep_a_crate() {
+    for seed in 0..50 {
+        let input = generate::generate(5, 200, seed).unwrap();
+        let parsed = day05::Day05.parse(&input).unwrap();
+        for answer in [day05::Day05.part_1(&parsed), day05::Day05.part_2(&parsed)] {
+            let answer = answer.to_string();
+            assert!(!answer.contains('\0'), "seed {}: {:?}", seed, answer);
+        }
+    }
+}
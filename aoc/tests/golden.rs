@@ -0,0 +1,174 @@
+//! Golden answers for every day, on the puzzle example and on the real input.
+//! Any change to an answer fails here.
+
+use common::{Answer, Part, Solution};
+use std::fs;
+use std::path::Path;
+
+fn check<S: Solution>(solution: &S, file: &str, part: Part, expected: impl Into<Answer>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(file);
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e));
+
+    let answer = common::solve(solution, &input, part).unwrap_or_else(|e| panic!("{}", e));
+    assert_eq!(answer, expected.into(), "{}, part {}", file, part);
+}
+
+#[test]
+fn day01() {
+    check(&day01::Day01, "day01/example.txt", Part::One, 24000);
+    check(&day01::Day01, "day01/example.txt", Part::Two, 45000);
+    check(&day01::Day01, "day01/input.txt", Part::One, 67633);
+    check(&day01::Day01, "day01/input.txt", Part::Two, 199628);
+}
+
+#[test]
+fn day02() {
+    check(&day02::Day02, "day02/example.txt", Part::One, 15);
+    check(&day02::Day02, "day02/example.txt", Part::Two, 12);
+    check(&day02::Day02, "day02/input.txt", Part::One, 11449);
+    check(&day02::Day02, "day02/input.txt", Part::Two, 13187);
+}
+
+#[test]
+fn day03() {
+    check(&day03::Day03, "day03/example.txt", Part::One, 157);
+    check(&day03::Day03, "day03/example.txt", Part::Two, 70);
+    check(&day03::Day03, "day03/input.txt", Part::One, 7766);
+    check(&day03::Day03, "day03/input.txt", Part::Two, 2415);
+}
+
+#[test]
+fn day04() {
+    check(&day04::Day04, "day04/example.txt", Part::One, 2);
+    check(&day04::Day04, "day04/example.txt", Part::Two, 4);
+    check(&day04::Day04, "day04/input.txt", Part::One, 485);
+    check(&day04::Day04, "day04/input.txt", Part::Two, 857);
+}
+
+#[test]
+fn day05() {
+    check(&day05::Day05, "day05/example.txt", Part::One, "CMZ");
+    check(&day05::Day05, "day05/example.txt", Part::Two, "MCD");
+    check(&day05::Day05, "day05/input.txt", Part::One, "WHTLRMZRC");
+    check(&day05::Day05, "day05/input.txt", Part::Two, "GMPMLWNMG");
+}
+
+#[test]
+fn day06() {
+    check(&day06::Day06, "day06/example.txt", Part::One, 7);
+    check(&day06::Day06, "day06/example.txt", Part::Two, 19);
+    check(&day06::Day06, "day06/input.txt", Part::One, 1850);
+    check(&day06::Day06, "day06/input.txt", Part::Two, 2823);
+}
+
+#[test]
+fn day07() {
+    check(&day07::Day07, "day07/example.txt", Part::One, 95437);
+    check(&day07::Day07, "day07/example.txt", Part::Two, 24933642);
+    check(&day07::Day07, "day07/input.txt", Part::One, 1915606);
+    check(&day07::Day07, "day07/input.txt", Part::Two, 5025657);
+}
+
+#[test]
+fn day08() {
+    check(&day08::Day08, "day08/example.txt", Part::One, 21);
+    check(&day08::Day08, "day08/example.txt", Part::Two, 8);
+    check(&day08::Day08, "day08/input.txt", Part::One, 1538);
+    check(&day08::Day08, "day08/input.txt", Part::Two, 496125);
+}
+
+#[test]
+fn day09() {
+    check(&day09::Day09, "day09/example.txt", Part::One, 13);
+    check(&day09::Day09, "day09/example.txt", Part::Two, 1);
+    check(&day09::Day09, "day09/input.txt", Part::One, 6498);
+    check(&day09::Day09, "day09/input.txt", Part::Two, 2531);
+}
+
+#[test]
+fn day10() {
+    check(&day10::Day10, "day10/example.txt", Part::One, 13140);
+    check(
+        &day10::Day10,
+        "day10/example.txt",
+        Part::Two,
+        [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ]
+        .join("\n"),
+    );
+    check(&day10::Day10, "day10/input.txt", Part::One, 17020);
+    check(
+        &day10::Day10,
+        "day10/input.txt",
+        Part::Two,
+        [
+            "###..#....####.####.####.#.....##..####.",
+            "#..#.#....#.......#.#....#....#..#.#....",
+            "#..#.#....###....#..###..#....#....###..",
+            "###..#....#.....#...#....#....#.##.#....",
+            "#.#..#....#....#....#....#....#..#.#....",
+            "#..#.####.####.####.#....####..###.####.",
+        ]
+        .join("\n"),
+    );
+}
+
+#[test]
+fn day11() {
+    check(&day11::Day11, "day11/example.txt", Part::One, 10605);
+    check(&day11::Day11, "day11/example.txt", Part::Two, 2713310158u64);
+    check(&day11::Day11, "day11/input.txt", Part::One, 102399);
+    check(&day11::Day11, "day11/input.txt", Part::Two, 23641658401u64);
+}
+
+#[test]
+fn day12() {
+    check(&day12::Day12, "day12/example.txt", Part::One, 31);
+    check(&day12::Day12, "day12/example.txt", Part::Two, 29);
+    check(&day12::Day12, "day12/input.txt", Part::One, 449);
+    check(&day12::Day12, "day12/input.txt", Part::Two, 443);
+}
+
+#[test]
+fn day13() {
+    check(&day13::Day13, "day13/example.txt", Part::One, 13);
+    check(&day13::Day13, "day13/example.txt", Part::Two, 140);
+    check(&day13::Day13, "day13/input.txt", Part::One, 5760);
+    check(&day13::Day13, "day13/input.txt", Part::Two, 26670);
+}
+
+#[test]
+fn day14() {
+    check(&day14::Day14, "day14/example.txt", Part::One, 24);
+    check(&day14::Day14, "day14/example.txt", Part::Two, 93);
+    check(&day14::Day14, "day14/input.txt", Part::One, 638);
+    check(&day14::Day14, "day14/input.txt", Part::Two, 31722);
+}
+
+#[test]
+fn day15() {
+    // The example asks about row 10 instead of row 2000000, and searches
+    // up to 20 instead of 4000000
+    let example = day15::Day15 { row: 10, limit: 20 };
+    check(&example, "day15/example.txt", Part::One, 26);
+    check(&example, "day15/example.txt", Part::Two, 56000011);
+    check(
+        &day15::Day15::default(),
+        "day15/input.txt",
+        Part::One,
+        5108096,
+    );
+    check(
+        &day15::Day15::default(),
+        "day15/input.txt",
+        Part::Two,
+        10553942650264u64,
+    );
+}
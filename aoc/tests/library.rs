@@ -0,0 +1,93 @@
+//! The day crates used as libraries, the way other tooling would.
+
+use common::Solution;
+use grid::{Direction, Point};
+use std::cmp::Ordering;
+
+#[test]
+fn day13_compare() {
+    let packet = |s: &str| s.parse::<day13::PacketData>().unwrap();
+
+    assert_eq!(
+        day13::compare(&packet("[1,1,3]"), &packet("[1,1,5]")),
+        Ordering::Less
+    );
+    assert_eq!(
+        day13::compare(&packet("[[4,4],4]"), &packet("[[4,4],4,4]")),
+        Ordering::Less
+    );
+    assert_eq!(
+        day13::compare(&packet("[9]"), &packet("[[8,7,6]]")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        day13::compare(&packet("[[1]]"), &packet("[1]")),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn day09_rope() {
+    let mut rope = day09::Rope::new(1);
+    rope.step(Direction::Right);
+    rope.step(Direction::Right);
+    assert_eq!(rope.tail(), Point::new(1, 0));
+}
+
+#[test]
+fn day10_cpu() {
+    let mut cpu = day10::SimpleCPU::new();
+    cpu.eval_instr(day10::Instruction::Addx(3));
+    cpu.clock();
+    assert_eq!((cpu.x, cpu.cycle), (4, 2));
+}
+
+#[test]
+fn day14_simulation() {
+    let paths = vec![vec![Point::new(499, 2), Point::new(501, 2)]];
+    let mut simulation = day14::Simulation::from_paths(&paths, false);
+    // One grain rests on the middle of the rock, the next rolls off the side
+    assert_eq!(simulation.simulate(), 1);
+}
+
+#[test]
+fn day01_stream() {
+    let file = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../day01/input.txt");
+    let reader = std::io::BufReader::new(std::fs::File::open(file).unwrap());
+
+    let top = day01::stream_top_n(reader, 3).unwrap();
+    assert_eq!(top[0].1, 67633);
+    assert_eq!(top.iter().map(|&(_, total)| total).sum::<u64>(), 199628);
+}
+
+#[test]
+fn day07_file_system() {
+    let input = "$ cd /\n$ ls\ndir a\n10 b.txt\n$ cd a\n$ ls\n5 c.txt\n";
+    let fs = day07::Day07.parse(input).unwrap();
+    let root = &fs.dirs["/"];
+    assert_eq!(root.get_total_size(), 15);
+    assert_eq!(root.dirs["a"].files["c.txt"].size, 5);
+    assert_eq!(day07::part_1(&fs), 5);
+}
+
+#[test]
+fn day11_monkeys() {
+    let operation = day11::Operation::Mul(day11::Arg::Old);
+    assert_eq!(operation.exec(7), 49);
+
+    let input = "Monkey 0:\n  Starting items: 1, 2\n  Operation: new = old + 3\n  Test: divisible by 2\n    If true: throw to monkey 1\n    If false: throw to monkey 1\n\nMonkey 1:\n  Starting items: \n  Operation: new = old * 2\n  Test: divisible by 3\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n";
+    let mut monkeys = day11::Day11.parse(input).unwrap();
+    assert_eq!(monkeys[0].divisible, 2);
+    day11::start_monkey_inspection(&mut monkeys, 1, |worry| worry);
+    assert_eq!(monkeys[0].inspection_amount, 2);
+}
+
+#[test]
+fn day15_sensors() {
+    let sensors = day15::Day15::default()
+        .parse("Sensor at x=0, y=0: closest beacon is at x=2, y=0\n")
+        .unwrap();
+    assert_eq!(sensors[0].m_distance, 2);
+    // x = -2..=2 on row 0, less the beacon itself
+    assert_eq!(day15::no_beacon_count(&sensors, 0), 4);
+}
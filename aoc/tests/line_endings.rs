@@ -0,0 +1,49 @@
+//! Every day gives the same answers whatever the line endings, with a byte
+//! order mark, trailing whitespace or no final newline.
+
+use common::{Part, Solution};
+use std::fs;
+use std::path::Path;
+
+// The input as a Windows editor might save it
+fn windows(input: &str) -> String {
+    let lines: Vec<String> = input.lines().map(|line| format!("{}  ", line)).collect();
+    format!("\u{feff}{}", lines.join("\r\n"))
+}
+
+fn same_answers<S: Solution>(solution: &S, day: u8, parts: &[Part]) {
+    for file in ["example.txt", "input.txt"] {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day{:02}", day))
+            .join(file);
+        let input = fs::read_to_string(&path).unwrap();
+
+        for &part in parts {
+            let unix = common::solve(solution, &input, part).unwrap();
+            let windows = common::solve(solution, &windows(&input), part)
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            assert_eq!(unix, windows, "{}, part {}", path.display(), part);
+        }
+    }
+}
+
+#[test]
+fn line_endings_dont_change_answers() {
+    let both = [Part::One, Part::Two];
+    same_answers(&day01::Day01, 1, &both);
+    same_answers(&day02::Day02, 2, &both);
+    same_answers(&day03::Day03, 3, &both);
+    same_answers(&day04::Day04, 4, &both);
+    same_answers(&day05::Day05, 5, &both);
+    same_answers(&day06::Day06, 6, &both);
+    same_answers(&day07::Day07, 7, &both);
+    same_answers(&day08::Day08, 8, &both);
+    same_answers(&day09::Day09, 9, &both);
+    same_answers(&day10::Day10, 10, &both);
+    same_answers(&day11::Day11, 11, &both);
+    same_answers(&day12::Day12, 12, &both);
+    same_answers(&day13::Day13, 13, &both);
+    same_answers(&day14::Day14, 14, &both);
+    same_answers(&day15::Day15::default(), 15, &both);
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The answer to one part of a puzzle. Serializes as a plain number or string.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(i64::try_from(n).expect("answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_number_test() {
+        assert_eq!(Answer::from(42u32), Answer::Number(42));
+        assert_eq!(Answer::from(23641658401u64), Answer::Number(23641658401));
+        assert_eq!(Answer::from(-1), Answer::Number(-1));
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(Answer::from(1850usize).to_string(), "1850");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+
+    #[test]
+    fn serialize_test() {
+        let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+        assert_eq!(json(Answer::Number(13140)), "13140");
+        assert_eq!(json(Answer::Text("##.\n.##".into())), "\"##.\\n.##\"");
+    }
+}
@@ -0,0 +1,93 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where a puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Str(String),
+}
+
+impl Input {
+    /// Interprets a command line argument: `-` is stdin, anything else a file path.
+    pub fn from_arg(arg: &str) -> Input {
+        if arg == "-" {
+            Input::Stdin
+        } else {
+            Input::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Opens the input for buffered reading.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Input::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+            Input::Stdin => Ok(Box::new(io::stdin().lock())),
+            Input::Str(s) => Ok(Box::new(Cursor::new(s.clone().into_bytes()))),
+        }
+    }
+
+    /// Reads the whole input into a string.
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Input::Str(s) => Ok(s.clone()),
+            _ => {
+                let mut s = String::new();
+                self.open()?.read_to_string(&mut s)?;
+                Ok(s)
+            }
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Input, Infallible> {
+        Ok(Input::from_arg(s))
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Str(_) => write!(f, "<string>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_arg_test() {
+        assert_eq!(Input::from_arg("-"), Input::Stdin);
+        assert_eq!(
+            Input::from_arg("day01/input.txt"),
+            Input::File(PathBuf::from("day01/input.txt"))
+        );
+    }
+
+    #[test]
+    fn read_str_test() {
+        let input = Input::Str("1000\n2000\n".to_string());
+        assert_eq!(input.read().unwrap(), "1000\n2000\n");
+
+        let lines: Vec<String> = input.open().unwrap().lines().map(|l| l.unwrap()).collect();
+        assert_eq!(lines, vec!["1000", "2000"]);
+    }
+
+    #[test]
+    fn read_missing_file_test() {
+        let input = Input::from_arg("does/not/exist.txt");
+        assert!(input.read().is_err());
+    }
+}
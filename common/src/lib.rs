@@ -0,0 +1,136 @@
+mod answer;
+mod input;
+pub mod parse;
+
+pub use answer::Answer;
+pub use input::Input;
+pub use parse::ParseError;
+
+use serde::{Serialize, Serializer};
+use std::env;
+use std::fmt;
+use std::process;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, info_span};
+
+/// A day's puzzle. The input is parsed once and both parts are solved
+/// from the parsed form.
+pub trait Solution {
+    /// Parsed puzzle input shared by both parts.
+    type Input;
+
+    /// Bumped whenever a change to the solution could change its answers,
+    /// so answers cached by an older version aren't used.
+    const VERSION: u32 = 1;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(&self, input: &Self::Input) -> Answer;
+
+    fn part_2(&self, input: &Self::Input) -> Answer;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+// Serializes as the part number
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+/// An answer along with how long it took to parse the input and solve the part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parses the puzzle input and solves one part of it.
+pub fn solve<S: Solution>(solution: &S, input: &str, part: Part) -> Result<Answer, ParseError> {
+    solve_timed(solution, input, part).map(|solved| solved.answer)
+}
+
+/// Like `solve`, but also times parsing and solving. Both are traced in
+/// their own span, with their times logged at info level.
+pub fn solve_timed<S: Solution>(
+    solution: &S,
+    input: &str,
+    part: Part,
+) -> Result<Solved, ParseError> {
+    let span = info_span!("parse", bytes = input.len()).entered();
+    let start = Instant::now();
+    let parsed = solution.parse(input).inspect_err(|e| {
+        debug!(error = %e, "could not parse input");
+    })?;
+    let parse_time = start.elapsed();
+    info!(elapsed = ?parse_time, "parsed");
+    drop(span);
+
+    let _span = info_span!("solve", %part).entered();
+    let start = Instant::now();
+    let answer = match part {
+        Part::One => solution.part_1(&parsed),
+        Part::Two => solution.part_2(&parsed),
+    };
+    let solve_time = start.elapsed();
+    info!(elapsed = ?solve_time, %answer, "solved");
+
+    Ok(Solved {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+/// Entry point shared by the dayNN binaries. Solves the input given as the
+/// first argument (`-` for stdin, `input.txt` if omitted) for the part given
+/// by the `part` environment variable (`part1` or `part2`).
+pub fn main<S: Solution>(solution: &S) {
+    let source = match env::args().nth(1) {
+        Some(arg) => Input::from_arg(&arg),
+        None => Input::File("input.txt".into()),
+    };
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("could not read {}: {}", source, e);
+            process::exit(1);
+        }
+    };
+    let part = match env::var("part") {
+        Ok(val) => val,
+        Err(_e) => "part1".to_string(),
+    };
+
+    let part = match part.as_str() {
+        "part1" => Part::One,
+        "part2" => Part::Two,
+        _ => return,
+    };
+
+    match solve(solution, &input, part) {
+        Ok(answer) => println!("{}", answer),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned when a puzzle input can't be parsed. Points at the
+/// offending line and column (both counted from 1).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error for input that ends before the puzzle is complete.
+    pub fn end_of_input(day: u8, input: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day,
+            line: input.lines().count() + 1,
+            column: 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    // day 9, line 3, column 3: invalid steps 'x': invalid digit found in string
+    //     U x
+    //       ^
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(
+                f,
+                "\n    {}\n    {:>width$}",
+                self.text,
+                "^",
+                width = self.column
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A numbered line of puzzle input, used to point parse errors at it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Error at a column of this line, counted in characters from 1.
+    pub fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error pointing at `token`, which should be a slice of this line.
+    /// Falls back to the first column for any other string.
+    pub fn error_at(&self, token: &str, message: impl Into<String>) -> ParseError {
+        self.error(self.column_of(token), message)
+    }
+
+    /// Parses `token`, a slice of this line, reporting it as an invalid
+    /// `what` if it doesn't parse.
+    pub fn parse<T>(&self, token: &str, what: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        token
+            .parse()
+            .map_err(|e| self.error_at(token, format!("invalid {} '{}': {}", what, token, e)))
+    }
+
+    fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+}
+
+/// Cleans up an input as it might come from another editor or OS: drops a
+/// byte order mark, turns CRLF and lone CR line endings into LF, trims
+/// trailing whitespace off every line and ends the input with a newline.
+/// Input that is already clean is returned as is.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let clean = !input.contains('\r')
+        && (input.is_empty() || input.ends_with('\n'))
+        && input.lines().all(|line| line.trim_end() == line);
+    if clean {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = String::with_capacity(input.len() + 1);
+    for line in input.replace("\r\n", "\n").replace('\r', "\n").lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+/// Numbered lines of a day's input.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+/// Numbered lines of a day's input, leaving out blank lines.
+pub fn non_blank_lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines(day, input).filter(|line| !line.is_blank())
+}
+
+/// Groups of lines separated by one or more blank lines.
+pub fn blocks(day: u8, input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks: Vec<Vec<Line>> = vec![];
+    let mut block: Vec<Line> = vec![];
+
+    for line in lines(day, input) {
+        if line.is_blank() {
+            if !block.is_empty() {
+                blocks.push(block);
+                block = vec![];
+            }
+        } else {
+            block.push(line);
+        }
+    }
+
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_at_token_test() {
+        let line = lines(9, "R 4\nU x\n").nth(1).unwrap();
+        let err = line.parse::<i32>(&line.text[2..], "steps").unwrap_err();
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 3);
+        assert_eq!(err.text, "U x");
+        assert_eq!(
+            err.to_string(),
+            "day 9, line 2, column 3: invalid steps 'x': invalid digit found in string\n    U x\n      ^"
+        );
+    }
+
+    #[test]
+    fn error_at_foreign_token_test() {
+        let line = lines(1, "abc").next().unwrap();
+        assert_eq!(line.error_at(&line.text[2..], "bad").column, 3);
+        assert_eq!(line.error_at(&String::from("c"), "bad").column, 1);
+    }
+
+    #[test]
+    fn blocks_test() {
+        let blocks = blocks(1, "1\n2\n\n3\n\n\n4\n");
+        let numbers: Vec<Vec<usize>> = blocks
+            .iter()
+            .map(|block| block.iter().map(|line| line.number).collect())
+            .collect();
+
+        assert_eq!(numbers, vec![vec![1, 2], vec![4], vec![7]]);
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(normalize("\u{feff}a \r\n\r\nb\t\r\n"), "a\n\nb\n");
+        assert_eq!(normalize("a\rb"), "a\nb\n");
+        assert_eq!(normalize(""), "");
+        assert!(matches!(normalize("a\n\nb\n"), Cow::Borrowed("a\n\nb\n")));
+    }
+
+    #[test]
+    fn end_of_input_test() {
+        let err = ParseError::end_of_input(3, "a\nb\n", "missing group");
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.to_string(), "day 3, line 3, column 1: missing group");
+    }
+}
@@ -0,0 +1,230 @@
+mod report;
+mod util;
+use common::parse::{self, Line};
+use common::{Answer, ParseError, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+use tracing::trace;
+
+pub use report::Report;
+
+pub struct Day01;
+
+// The calories of each snack one elf carries, in the order they're listed
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Elf {
+    pub items: Vec<u32>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.items.iter().map(|&item| u64::from(item)).sum()
+    }
+}
+
+// Keeps the `k` largest totals pushed so far, along with the index of the
+// elf carrying them. Ties go to the elf that came first
+pub struct TopN {
+    k: usize,
+    // Min-heap, so the smallest kept total is the one to drop
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopN {
+    pub fn new(k: usize) -> TopN {
+        TopN {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, index: usize, total: u64) {
+        self.heap.push(Reverse((total, Reverse(index))));
+        let dropped = if self.heap.len() > self.k {
+            self.heap.pop().map(|Reverse((_, Reverse(i)))| i)
+        } else {
+            None
+        };
+        if dropped != Some(index) {
+            trace!(elf = index, calories = total, "elf in the top so far");
+        }
+    }
+
+    // (elf index, total) pairs, largest total first
+    pub fn into_sorted(self) -> Vec<(usize, u64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| (index, total))
+            .collect()
+    }
+}
+
+// The `k` elves carrying the most calories as (index, total), most first
+pub fn top_n(elves: &[Elf], k: usize) -> Vec<(usize, u64)> {
+    let mut top = TopN::new(k);
+    for (index, elf) in elves.iter().enumerate() {
+        top.push(index, elf.total());
+    }
+    top.into_sorted()
+}
+
+// Reads the input a line at a time, calling `on_elf` with each elf's index
+// and total. Only the current line and running total are kept, so inputs of
+// any size can be streamed. Returns the number of elves.
+// Parse errors come back as `InvalidData` errors wrapping a `ParseError`
+pub fn stream_totals(
+    mut reader: impl BufRead,
+    mut on_elf: impl FnMut(usize, u64),
+) -> io::Result<usize> {
+    let mut line = String::new();
+    let mut number = 0;
+    let mut elves = 0;
+    // The running total, or `None` between elves
+    let mut total: Option<u64> = None;
+
+    loop {
+        line.clear();
+        let at_end = reader.read_line(&mut line)? == 0;
+        number += 1;
+
+        let text = line.strip_prefix('\u{feff}').unwrap_or(&line).trim_end();
+        if at_end || text.is_empty() {
+            if let Some(total) = total.take() {
+                on_elf(elves, total);
+                elves += 1;
+            }
+            if at_end {
+                return Ok(elves);
+            }
+            continue;
+        }
+
+        let line = Line {
+            day: 1,
+            number,
+            text,
+        };
+        let item: u32 = line
+            .parse(text.trim_start(), "calories")
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        total = Some(total.unwrap_or(0) + u64::from(item));
+    }
+}
+
+// `top_n` over a streamed input
+pub fn stream_top_n(reader: impl BufRead, k: usize) -> io::Result<Vec<(usize, u64)>> {
+    let mut top = TopN::new(k);
+    stream_totals(reader, |index, total| top.push(index, total))?;
+    Ok(top.into_sorted())
+}
+
+impl Solution for Day01 {
+    type Input = Vec<Elf>;
+
+    // 2: zero calorie snacks no longer separate elves
+    const VERSION: u32 = 2;
+
+    fn parse(&self, input: &str) -> Result<Vec<Elf>, ParseError> {
+        let input = &parse::normalize(input);
+        util::read_elves(input)
+    }
+
+    fn part_1(&self, elves: &Vec<Elf>) -> Answer {
+        part_1(elves).into()
+    }
+
+    fn part_2(&self, elves: &Vec<Elf>) -> Answer {
+        part_2(elves).into()
+    }
+}
+
+pub fn part_1(elves: &[Elf]) -> u64 {
+    top_n(elves, 1).iter().map(|&(_, total)| total).sum()
+}
+
+pub fn part_2(elves: &[Elf]) -> u64 {
+    top_n(elves, 3).iter().map(|&(_, total)| total).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elves(input: &str) -> Vec<Elf> {
+        Day01.parse(input).unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        // No blank line after the last elf, and a snack with no calories
+        let parsed = elves("1000\n2000\n\n0\n4000\n\n\n5000");
+        assert_eq!(
+            parsed,
+            vec![
+                Elf {
+                    items: vec![1000, 2000]
+                },
+                Elf {
+                    items: vec![0, 4000]
+                },
+                Elf { items: vec![5000] },
+            ]
+        );
+        assert_eq!(elves(""), vec![]);
+    }
+
+    #[test]
+    fn top_n_test() {
+        let parsed = elves("1\n2\n\n3\n\n4\n\n3\n");
+        assert_eq!(top_n(&parsed, 3), vec![(2, 4), (0, 3), (1, 3)]);
+        assert_eq!(top_n(&parsed, 2), vec![(2, 4), (0, 3)]);
+        assert_eq!(top_n(&parsed, 10).len(), 4);
+        assert_eq!(top_n(&parsed, 0), vec![]);
+    }
+
+    #[test]
+    fn fewer_than_three_elves_test() {
+        let parsed = elves("5\n\n7\n");
+        assert_eq!((part_1(&parsed), part_2(&parsed)), (7, 12));
+        assert_eq!((part_1(&[]), part_2(&[])), (0, 0));
+    }
+
+    #[test]
+    fn report_test() {
+        let parsed = elves("1\n2\n\n4\n\n2\n1\n\n10\n\n5\n");
+        let report = Report::new(&parsed).unwrap();
+
+        assert_eq!((report.elves, report.median), (5, 4));
+        assert_eq!(report.mean, 5.0);
+        assert_eq!(report.percentiles[0], (10, 3));
+        assert_eq!(report.percentiles[4], (99, 10));
+        assert_eq!(report.item_counts[&1], 3);
+        assert_eq!(report.item_counts[&2], 2);
+        assert_eq!(report.duplicates, vec![vec![0, 2]]);
+        assert_eq!(report.bins(3), vec![(3, 5, 4), (6, 8, 0), (9, 11, 1)]);
+        assert_eq!(Report::new(&[]), None);
+    }
+
+    #[test]
+    fn stream_test() {
+        let input = "1\n2\n\n0\n4\n\n\n3\n\n4";
+        assert_eq!(
+            stream_top_n(input.as_bytes(), 3).unwrap(),
+            top_n(&elves(input), 3)
+        );
+
+        let crlf = "\u{feff}1\r\n2\r\n\r\n4000000000\r\n4000000000\r\n";
+        assert_eq!(
+            stream_top_n(crlf.as_bytes(), 1).unwrap(),
+            vec![(1, 8_000_000_000)]
+        );
+
+        let e = stream_top_n("1\n\n2\nx\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(e
+            .to_string()
+            .starts_with("day 1, line 4, column 1: invalid calories 'x'"));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::Elf;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+// Percentiles listed in the report
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+// Statistics over the elves' totals and inventories
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: u64,
+    // (percentile, total), by the nearest rank
+    pub percentiles: Vec<(u8, u64)>,
+    // How many elves carry each number of items
+    pub item_counts: BTreeMap<usize, usize>,
+    // Indices of elves carrying the same items, in any order. Each group is
+    // sorted and the groups are ordered by their first elf
+    pub duplicates: Vec<Vec<usize>>,
+    // Every total, sorted
+    totals: Vec<u64>,
+}
+
+// The total at least `p` percent of the sorted totals are at or below
+fn percentile(sorted: &[u64], p: u8) -> u64 {
+    let rank = (p as usize * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+impl Report {
+    // Returns `None` if there are no elves
+    pub fn new(elves: &[Elf]) -> Option<Report> {
+        if elves.is_empty() {
+            return None;
+        }
+
+        let mut totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+        totals.sort_unstable();
+
+        let mut item_counts = BTreeMap::new();
+        let mut inventories: HashMap<Vec<u32>, Vec<usize>> = HashMap::new();
+        for (index, elf) in elves.iter().enumerate() {
+            *item_counts.entry(elf.items.len()).or_default() += 1;
+
+            let mut items = elf.items.clone();
+            items.sort_unstable();
+            inventories.entry(items).or_default().push(index);
+        }
+        let mut duplicates: Vec<Vec<usize>> = inventories
+            .into_values()
+            .filter(|group| group.len() > 1)
+            .collect();
+        duplicates.sort();
+
+        Some(Report {
+            elves: elves.len(),
+            mean: totals.iter().sum::<u64>() as f64 / totals.len() as f64,
+            median: percentile(&totals, 50),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&totals, p)))
+                .collect(),
+            item_counts,
+            duplicates,
+            totals,
+        })
+    }
+
+    // Counts of totals in `bins` equally wide ranges from the smallest
+    // total to the largest, as (first, last, count)
+    pub fn bins(&self, bins: usize) -> Vec<(u64, u64, usize)> {
+        let (min, max) = (self.totals[0], self.totals[self.totals.len() - 1]);
+        let size = (max - min) / bins.max(1) as u64 + 1;
+
+        let mut counts = vec![0; ((max - min) / size + 1) as usize];
+        for total in &self.totals {
+            counts[((total - min) / size) as usize] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let first = min + i as u64 * size;
+                (first, first + size - 1, count)
+            })
+            .collect()
+    }
+
+    // The bins drawn as bars of `#`, the longest `width` characters long
+    pub fn histogram(&self, bins: usize, width: usize) -> String {
+        let bins = self.bins(bins);
+        let most = bins.iter().map(|&(_, _, count)| count).max().unwrap_or(0);
+
+        bins.iter()
+            .map(|&(first, last, count)| {
+                // Any elves at all get at least one `#`
+                let bar = (count * width).div_ceil(most.max(1));
+                format!(
+                    "{:>10} - {:<10} {:>6} {}\n",
+                    first,
+                    last,
+                    count,
+                    "#".repeat(bar)
+                )
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {}", self.median)?;
+        for (p, total) in &self.percentiles {
+            writeln!(f, "p{}: {}", p, total)?;
+        }
+
+        writeln!(f, "\nItems per elf:")?;
+        writeln!(f, "{:>6} {:>6}", "items", "elves")?;
+        for (items, elves) in &self.item_counts {
+            writeln!(f, "{:>6} {:>6}", items, elves)?;
+        }
+
+        writeln!(f, "\nTotals:")?;
+        write!(f, "{}", self.histogram(10, 40))?;
+
+        write!(f, "\nDuplicate inventories:")?;
+        if self.duplicates.is_empty() {
+            return writeln!(f, " none");
+        }
+        writeln!(f)?;
+        for group in &self.duplicates {
+            let elves: Vec<String> = group.iter().map(|i| (i + 1).to_string()).collect();
+            writeln!(f, "    elves {}", elves.join(", "))?;
+        }
+        Ok(())
+    }
+}
@@ -1,23 +1,17 @@
-use std::fs::File;
-use std::io::BufReader;
-use std::io::BufRead;
+use crate::Elf;
+use common::parse;
+use common::ParseError;
 
-pub fn read_lines(path: &str) -> Vec<i32> {
-    let file = File::open(path).expect("not found");
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|line|
-            if let Ok(l) = line {
-                if let Ok(val) = l.parse::<i32>() {
-                    val
-                } else {
-                    -1
-                }
-            } else {
-                -1
-            }
-        )
+// One elf per group of lines, the groups separated by blank lines
+pub fn read_elves(input: &str) -> Result<Vec<Elf>, ParseError> {
+    parse::blocks(1, input)
+        .iter()
+        .map(|block| {
+            let items = block
+                .iter()
+                .map(|line| line.parse(line.text.trim(), "calories"))
+                .collect::<Result<_, _>>()?;
+            Ok(Elf { items })
+        })
         .collect()
-}
\ No newline at end of file
+}
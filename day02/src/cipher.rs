@@ -0,0 +1,128 @@
+use crate::{Outcome, Rules, Shape};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+// What the symbols of the strategy guide stand for. The second column is
+// read as a shape in part 1 and as an outcome in part 2, so `shape` and
+// `outcome` must use the same symbols
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cipher {
+    // Symbol to the name of the elf's shape
+    pub elf: BTreeMap<String, String>,
+    // Symbol to the name of the shape to play
+    pub shape: BTreeMap<String, String>,
+    pub outcome: BTreeMap<String, Outcome>,
+}
+
+impl Cipher {
+    // A, B, C for the elf and X, Y, Z for the human, as in the puzzle
+    pub fn standard() -> Cipher {
+        Cipher::from_toml(include_str!("../cipher.toml")).unwrap()
+    }
+
+    pub fn from_toml(s: &str) -> Result<Cipher, String> {
+        toml::from_str(s).map_err(|e| e.message().to_string())
+    }
+
+    pub fn from_json(s: &str) -> Result<Cipher, String> {
+        serde_json::from_str(s).map_err(|e| e.to_string())
+    }
+
+    // Reads a `.toml` or `.json` file
+    pub fn load(path: &Path) -> Result<Cipher, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Cipher::from_toml(&contents),
+            Some("json") => Cipher::from_json(&contents),
+            _ => Err("expected a .toml or .json file".to_string()),
+        }
+    }
+
+    // Fails if a shape isn't in `rules` or the second column symbols don't match up
+    pub fn check(&self, rules: &Rules) -> Result<(), String> {
+        for name in self.elf.values().chain(self.shape.values()) {
+            if rules.shape(name).is_none() {
+                return Err(format!("unknown shape '{}'", name));
+            }
+        }
+        if !self.shape.keys().eq(self.outcome.keys()) {
+            return Err("[shape] and [outcome] must have the same symbols".to_string());
+        }
+        Ok(())
+    }
+
+    // `None` for symbols that aren't in the cipher
+    pub fn elf(&self, rules: &Rules, symbol: &str) -> Option<Shape> {
+        rules.shape(self.elf.get(symbol)?)
+    }
+
+    pub fn human(&self, rules: &Rules, symbol: &str) -> Option<(Shape, Outcome)> {
+        let shape = rules.shape(self.shape.get(symbol)?)?;
+        Some((shape, *self.outcome.get(symbol)?))
+    }
+}
+
+// "X, Y or Z"
+pub fn expected(symbols: &BTreeMap<String, impl Sized>) -> String {
+    let symbols: Vec<&str> = symbols.keys().map(String::as_str).collect();
+    match symbols.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => "nothing".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_guide, score_outcomes, score_shapes};
+
+    #[test]
+    fn cipher_test() {
+        let rules = Rules::rpsls();
+        let cipher = Cipher::from_json(
+            r#"{
+                "elf": {"🪨": "rock", "🖖": "Spock"},
+                "shape": {"lose": "lizard", "win": "paper"},
+                "outcome": {"lose": "lose", "win": "win"}
+            }"#,
+        )
+        .unwrap();
+        cipher.check(&rules).unwrap();
+
+        let rounds = parse_guide("🪨 win\n🖖 lose\n", &rules, &cipher).unwrap();
+        // Paper beats rock, lizard beats Spock
+        assert_eq!(score_shapes(&rules, &rounds), 2 + 6 + 4 + 6);
+        // Spock beats rock and is worth more than paper, scissors lose to
+        // Spock and are worth more than rock
+        assert_eq!(score_outcomes(&rules, &rounds), Some(5 + 6 + 3));
+
+        let e = parse_guide("🪨 draw\n", &rules, &cipher).err().unwrap();
+        assert_eq!(e.column, 3);
+        assert_eq!(e.message, "expected lose or win, found 'draw'");
+    }
+
+    #[test]
+    fn invalid_cipher_test() {
+        let rules = Rules::standard();
+        assert!(Cipher::standard().check(&rules).is_ok());
+        assert!(Cipher::standard().check(&Rules::rpsls()).is_ok());
+
+        let unknown_shape = "[elf]\nA = \"Spock\"\n[shape]\n[outcome]\n";
+        assert!(Cipher::from_toml(unknown_shape)
+            .unwrap()
+            .check(&rules)
+            .is_err());
+
+        let mismatched = "[elf]\n[shape]\nX = \"rock\"\n[outcome]\nY = \"win\"\n";
+        assert!(Cipher::from_toml(mismatched)
+            .unwrap()
+            .check(&rules)
+            .is_err());
+
+        assert!(Cipher::from_toml("[elf]\n[shape]\n[outcome]\nX = \"tie\"\n").is_err());
+    }
+}
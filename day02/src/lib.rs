@@ -0,0 +1,94 @@
+use common::parse::{self, Line};
+use common::{Answer, ParseError, Solution};
+
+mod cipher;
+mod rules;
+pub use cipher::Cipher;
+pub use rules::{Outcome, Rules, Shape};
+
+// One round of the strategy guide. The second column is read as the
+// human's shape in part 1 and as the outcome to aim for in part 2.
+pub struct Round {
+    pub elf: Shape,
+    pub human: Shape,
+    pub outcome: Outcome,
+}
+
+impl Round {
+    fn parse(line: Line, rules: &Rules, cipher: &Cipher) -> Result<Round, ParseError> {
+        let mut columns = line.text.split_whitespace();
+        let (elf, human) = match (columns.next(), columns.next(), columns.next()) {
+            (Some(elf), Some(human), None) => (elf, human),
+            _ => return Err(line.error(1, "expected two columns separated by a space")),
+        };
+
+        let elf = cipher.elf(rules, elf).ok_or_else(|| {
+            let expected = cipher::expected(&cipher.elf);
+            line.error_at(elf, format!("expected {}, found '{}'", expected, elf))
+        })?;
+        let (human, outcome) = cipher.human(rules, human).ok_or_else(|| {
+            let expected = cipher::expected(&cipher.shape);
+            line.error_at(human, format!("expected {}, found '{}'", expected, human))
+        })?;
+
+        Ok(Round {
+            elf,
+            human,
+            outcome,
+        })
+    }
+}
+
+// Parses a strategy guide written with `cipher`, for a game played by `rules`
+pub fn parse_guide(input: &str, rules: &Rules, cipher: &Cipher) -> Result<Vec<Round>, ParseError> {
+    let input = &parse::normalize(input);
+    parse::non_blank_lines(2, input)
+        .map(|line| Round::parse(line, rules, cipher))
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Round>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Round>, ParseError> {
+        parse_guide(input, &Rules::standard(), &Cipher::standard())
+    }
+
+    fn part_1(&self, input: &Vec<Round>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Vec<Round>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(rounds: &[Round]) -> i32 {
+    score_shapes(&Rules::standard(), rounds)
+}
+
+pub fn part_2(rounds: &[Round]) -> i32 {
+    score_outcomes(&Rules::standard(), rounds).expect("every outcome is possible")
+}
+
+// Total score playing the human's shape in every round
+pub fn score_shapes(rules: &Rules, rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|round| rules.score(round.human, round.elf))
+        .sum()
+}
+
+// Total score playing for the outcome in every round, or `None` if some
+// outcome can't be had against the elf's shape
+pub fn score_outcomes(rules: &Rules, rounds: &[Round]) -> Option<i32> {
+    rounds
+        .iter()
+        .map(|round| {
+            let human = rules.force_outcome(round.elf, round.outcome)?;
+            Some(rules.score(human, round.elf))
+        })
+        .sum()
+}
@@ -0,0 +1,202 @@
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+// A shape, by its position in the rule table
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shape(pub usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+// A game of shapes where, for any two different shapes, exactly one beats
+// the other. Each round scores the value of the shape played plus the value
+// of the outcome
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    values: Vec<i32>,
+    // beats[a][b] is true if shape a beats shape b
+    beats: Vec<Vec<bool>>,
+    // Values of losing, drawing and winning
+    outcome_values: [i32; 3],
+}
+
+impl Rules {
+    // `shapes` are (name, value) pairs, `beats` (winner, loser) pairs of names
+    // and `outcome_values` the values of losing, drawing and winning.
+    // Fails unless every two shapes have exactly one winner
+    pub fn new(
+        shapes: &[(&str, i32)],
+        beats: &[(&str, &str)],
+        outcome_values: [i32; 3],
+    ) -> Result<Rules, String> {
+        let mut index = HashMap::new();
+        for (i, &(name, _)) in shapes.iter().enumerate() {
+            if index.insert(name, i).is_some() {
+                return Err(format!("shape '{}' is listed twice", name));
+            }
+        }
+        let shape = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| format!("unknown shape '{}'", name))
+        };
+
+        let mut table = vec![vec![false; shapes.len()]; shapes.len()];
+        for &(winner, loser) in beats {
+            let (winner, loser) = (shape(winner)?, shape(loser)?);
+            if winner == loser {
+                return Err(format!("'{}' can't beat itself", shapes[winner].0));
+            }
+            table[winner][loser] = true;
+        }
+
+        for a in 0..shapes.len() {
+            for b in a + 1..shapes.len() {
+                if table[a][b] == table[b][a] {
+                    return Err(format!(
+                        "'{}' and '{}' must have exactly one winner",
+                        shapes[a].0, shapes[b].0
+                    ));
+                }
+            }
+        }
+
+        Ok(Rules {
+            names: shapes.iter().map(|&(name, _)| name.to_string()).collect(),
+            values: shapes.iter().map(|&(_, value)| value).collect(),
+            beats: table,
+            outcome_values,
+        })
+    }
+
+    // Every shape beats the half of the other shapes just before it, going
+    // round in a circle. Needs an odd number of shapes
+    pub fn cyclic(shapes: &[(&str, i32)], outcome_values: [i32; 3]) -> Result<Rules, String> {
+        let n = shapes.len();
+        let mut beats = vec![];
+        for winner in 0..n {
+            for step in 1..=n / 2 {
+                beats.push((shapes[winner].0, shapes[(winner + n - step) % n].0));
+            }
+        }
+        Rules::new(shapes, &beats, outcome_values)
+    }
+
+    // Rock, paper, scissors as scored by the elves
+    pub fn standard() -> Rules {
+        Rules::cyclic(&[("rock", 1), ("paper", 2), ("scissors", 3)], [0, 3, 6]).unwrap()
+    }
+
+    // Rock, paper, scissors, lizard, Spock. Rock, paper and scissors keep
+    // their values, lizard is worth 4 and Spock 5. Listed in the order of
+    // the cycle, where each shape beats the two before it
+    pub fn rpsls() -> Rules {
+        let shapes = [
+            ("rock", 1),
+            ("Spock", 5),
+            ("paper", 2),
+            ("lizard", 4),
+            ("scissors", 3),
+        ];
+        Rules::cyclic(&shapes, [0, 3, 6]).unwrap()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.names.len()).map(Shape)
+    }
+
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    // From `mine`'s perspective
+    pub fn outcome(&self, mine: Shape, theirs: Shape) -> Outcome {
+        if self.beats[mine.0][theirs.0] {
+            Outcome::Win
+        } else if self.beats[theirs.0][mine.0] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // The shape to play against `theirs` to get `outcome`. When several
+    // shapes would do, the one worth the most, and `None` if none would
+    pub fn force_outcome(&self, theirs: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&mine| self.outcome(mine, theirs) == outcome)
+            .max_by_key(|&mine| (self.values[mine.0], Reverse(mine.0)))
+    }
+
+    pub fn score(&self, mine: Shape, theirs: Shape) -> i32 {
+        let outcome = self.outcome(mine, theirs);
+        self.values[mine.0] + self.outcome_values[outcome as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Shapes by their position in `Rules::standard()`
+    const ROCK: Shape = Shape(0);
+    const PAPER: Shape = Shape(1);
+    const SCISSORS: Shape = Shape(2);
+
+    #[test]
+    fn standard_test() {
+        let rules = Rules::standard();
+        assert_eq!(rules.outcome(PAPER, ROCK), Outcome::Win);
+        assert_eq!(rules.outcome(ROCK, PAPER), Outcome::Lose);
+        assert_eq!(rules.outcome(SCISSORS, SCISSORS), Outcome::Draw);
+        assert_eq!(rules.force_outcome(ROCK, Outcome::Lose), Some(SCISSORS));
+        assert_eq!(rules.score(ROCK, SCISSORS), 7);
+    }
+
+    #[test]
+    fn rpsls_test() {
+        let rules = Rules::rpsls();
+        let shape = |name| rules.shape(name).unwrap();
+        let wins = |a, b| rules.outcome(shape(a), shape(b)) == Outcome::Win;
+
+        assert!(wins("scissors", "paper"));
+        assert!(wins("paper", "rock"));
+        assert!(wins("rock", "lizard"));
+        assert!(wins("lizard", "Spock"));
+        assert!(wins("Spock", "scissors"));
+        assert!(wins("scissors", "lizard"));
+        assert!(wins("lizard", "paper"));
+        assert!(wins("paper", "Spock"));
+        assert!(wins("Spock", "rock"));
+        assert!(wins("rock", "scissors"));
+
+        // Paper and Spock both beat rock, Spock is worth more
+        let forced = rules.force_outcome(shape("rock"), Outcome::Win).unwrap();
+        assert_eq!(rules.name(forced), "Spock");
+        assert_eq!(rules.score(shape("lizard"), shape("paper")), 4 + 6);
+    }
+
+    #[test]
+    fn invalid_rules_test() {
+        let shapes = [("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+        assert!(Rules::cyclic(&shapes, [0, 3, 6]).is_err());
+        assert!(Rules::new(&shapes[..2], &[("a", "b"), ("b", "a")], [0, 3, 6]).is_err());
+        assert!(Rules::new(&shapes[..2], &[("a", "x")], [0, 3, 6]).is_err());
+
+        // Nothing beats a shape that beats everything
+        let rules = Rules::new(&shapes[..2], &[("a", "b")], [0, 3, 6]).unwrap();
+        assert_eq!(rules.force_outcome(Shape(0), Outcome::Win), None);
+    }
+}
@@ -0,0 +1,114 @@
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    // One rucksack per line
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        let input = &parse::normalize(input);
+        let rucksacks = parse::non_blank_lines(3, input)
+            .map(|line| {
+                let items = line.text.trim();
+                if let Some((i, c)) = items.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                    return Err(line.error_at(&items[i..], format!("invalid item '{}'", c)));
+                }
+                if items.len() % 2 != 0 {
+                    return Err(line.error_at(
+                        items,
+                        format!("{} items can't be split into two compartments", items.len()),
+                    ));
+                }
+                Ok(items.to_string())
+            })
+            .collect::<Result<Vec<String>, ParseError>>()?;
+
+        if rucksacks.len() % 3 != 0 {
+            return Err(ParseError::end_of_input(
+                3,
+                input,
+                format!(
+                    "{} rucksacks can't be split into groups of three",
+                    rucksacks.len()
+                ),
+            ));
+        }
+        Ok(rucksacks)
+    }
+
+    fn part_1(&self, input: &Vec<String>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Vec<String>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(lines: &[String]) -> i32 {
+    lines
+        .iter()
+        .map(|line| {
+            // Split line into two, look for duplicate over two compartments
+
+            let mut rucksack = HashSet::new();
+            let compartment = line.split_at(line.len() / 2);
+
+            // Insert everything in compartment 0
+            for b in compartment.0.bytes() {
+                rucksack.insert(b);
+            }
+
+            let mut dup: u8 = 0;
+
+            for b in compartment.1.bytes() {
+                if rucksack.contains(&b) {
+                    if (96..=122).contains(&b) {
+                        dup = b - 96;
+                    } else {
+                        dup = b - 38;
+                    }
+                }
+            }
+            i32::from(dup)
+        })
+        .collect::<Vec<i32>>()
+        .iter()
+        .sum()
+}
+
+pub fn part_2(lines: &[String]) -> i32 {
+    lines
+        .chunks(3)
+        .map(|chunk| {
+            let mut rucksack_a = HashSet::new();
+            let mut rucksack_b = HashSet::new();
+
+            for b in chunk.first().unwrap().bytes() {
+                rucksack_a.insert(b);
+            }
+
+            for b in chunk.get(1).unwrap().bytes() {
+                rucksack_b.insert(b);
+            }
+
+            let mut dup: u8 = 0;
+
+            for b in chunk.get(2).unwrap().bytes() {
+                if rucksack_a.contains(&b) && rucksack_b.contains(&b) {
+                    if (96..=122).contains(&b) {
+                        dup = b - 96;
+                    } else {
+                        dup = b - 38;
+                    }
+                }
+            }
+            i32::from(dup)
+        })
+        .collect::<Vec<i32>>()
+        .iter()
+        .sum()
+}
@@ -0,0 +1,78 @@
+use common::parse::{self, Line};
+use common::{Answer, ParseError, Solution};
+use std::ops::Range;
+
+// Section range such as `2-4`
+fn parse_range(line: &Line, range: &str) -> Result<Range<i32>, ParseError> {
+    let (start, end) = range.split_once("-").ok_or_else(|| {
+        line.error_at(
+            range,
+            format!("expected a range like 2-4, found '{}'", range),
+        )
+    })?;
+
+    Ok(Range {
+        start: line.parse(start, "section")?,
+        end: line.parse(end, "section")?,
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    // One pair of section assignments per line
+    type Input = Vec<(Range<i32>, Range<i32>)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Range<i32>, Range<i32>)>, ParseError> {
+        let input = &parse::normalize(input);
+        parse::non_blank_lines(4, input)
+            .map(|line| {
+                let (a, b) = line
+                    .text
+                    .trim()
+                    .split_once(",")
+                    .ok_or_else(|| line.error(1, "expected two comma separated ranges"))?;
+                Ok((parse_range(&line, a)?, parse_range(&line, b)?))
+            })
+            .collect()
+    }
+
+    fn part_1(&self, input: &Vec<(Range<i32>, Range<i32>)>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Vec<(Range<i32>, Range<i32>)>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(pairs: &[(Range<i32>, Range<i32>)]) -> i32 {
+    pairs
+        .iter()
+        .map(|(a, b)| {
+            if (a.start <= b.start && a.end >= b.end) || (b.start <= a.start && b.end >= a.end) {
+                1
+            } else {
+                0
+            }
+        })
+        .collect::<Vec<i32>>()
+        .iter()
+        .sum()
+}
+
+pub fn part_2(pairs: &[(Range<i32>, Range<i32>)]) -> i32 {
+    pairs
+        .iter()
+        .map(|(a, b)| {
+            if (a.start <= b.start && a.end >= b.start) || (b.start <= a.start && b.end >= a.start)
+            {
+                1
+            } else {
+                0
+            }
+        })
+        .collect::<Vec<i32>>()
+        .iter()
+        .sum()
+}
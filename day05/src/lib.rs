@@ -0,0 +1,282 @@
+use common::parse::{self, Line};
+use common::{Answer, ParseError, Solution};
+use std::fmt;
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Crate {
+    pub tag: char,
+}
+
+impl Crate {
+    pub fn parse_crate_line(line: &str) -> Vec<Crate> {
+        line.chars()
+            .skip(1) // skip first '['
+            .step_by(4) // step to every tag
+            .map(|c| Crate { tag: c })
+            .collect::<Vec<Crate>>()
+    }
+}
+
+impl fmt::Display for Crate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.tag)
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Instruction {
+    pub move_n: usize,
+    pub from_stack: usize,
+    pub to_stack: usize,
+}
+
+impl Instruction {
+    // move x from y to z
+    pub fn parse_instr(line: &Line) -> Result<Instruction, ParseError> {
+        let words: Vec<&str> = line.text.split_whitespace().collect();
+        match words[..] {
+            ["move", move_n, "from", from_stack, "to", to_stack] => Ok(Instruction {
+                move_n: line.parse(move_n, "crate count")?,
+                from_stack: line.parse(from_stack, "stack")?,
+                to_stack: line.parse(to_stack, "stack")?,
+            }),
+            _ => Err(line.error(1, "expected 'move N from A to B'")),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.move_n, self.from_stack, self.to_stack
+        )
+    }
+}
+
+pub struct Day05;
+
+// Starting crate stacks and the rearrangement procedure
+pub struct Supplies {
+    pub crate_stacks: Vec<Vec<Crate>>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Solution for Day05 {
+    type Input = Supplies;
+
+    fn parse(&self, input: &str) -> Result<Supplies, ParseError> {
+        let input = &parse::normalize(input);
+        let blocks = parse::blocks(5, input);
+        let (crates, instructions) = match &blocks[..] {
+            [crates, instructions] => (crates, instructions),
+            [_] => {
+                return Err(ParseError::end_of_input(
+                    5,
+                    input,
+                    "expected a blank line between the crates and the procedure",
+                ))
+            }
+            [] => return Err(ParseError::end_of_input(5, input, "expected crate stacks")),
+            [_, _, extra, ..] => return Err(extra[0].error(1, "unexpected third section")),
+        };
+
+        // The last line of the drawing numbers the stacks
+        let labels = crates.last().unwrap();
+        let stack_count = labels.text.split_whitespace().count();
+        if let Some(label) = labels
+            .text
+            .split_whitespace()
+            .find(|l| l.parse::<usize>().is_err())
+        {
+            return Err(labels.error_at(label, format!("invalid stack number '{}'", label)));
+        }
+
+        let mut crate_stacks: Vec<Vec<Crate>> = vec![Vec::new(); stack_count];
+
+        for line in crates.iter().rev().skip(1) {
+            for (i, c) in Crate::parse_crate_line(line.text).iter().enumerate() {
+                if c.tag.is_alphabetic() {
+                    if i >= stack_count {
+                        return Err(line.error(4 * i + 2, "crate outside of the numbered stacks"));
+                    }
+                    crate_stacks[i].push(*c);
+                }
+            }
+        }
+
+        // Follow the stack heights so no move takes more crates than a
+        // stack holds
+        let mut heights: Vec<usize> = crate_stacks.iter().map(Vec::len).collect();
+        let instructions = instructions
+            .iter()
+            .map(|line| {
+                let instr = Instruction::parse_instr(line)?;
+                for stack in [instr.from_stack, instr.to_stack] {
+                    if stack == 0 || stack > stack_count {
+                        return Err(line.error(
+                            1,
+                            format!("stack {} is not between 1 and {}", stack, stack_count),
+                        ));
+                    }
+                }
+                if instr.from_stack == instr.to_stack {
+                    return Err(line.error(1, "can't move crates from a stack onto itself"));
+                }
+
+                let (from, to) = (instr.from_stack - 1, instr.to_stack - 1);
+                if instr.move_n > heights[from] {
+                    let count = line.text.split_whitespace().nth(1).unwrap();
+                    return Err(line.error_at(
+                        count,
+                        format!(
+                            "can't move {} crates from stack {}, which holds {}",
+                            instr.move_n, instr.from_stack, heights[from]
+                        ),
+                    ));
+                }
+                heights[from] -= instr.move_n;
+                heights[to] += instr.move_n;
+                Ok(instr)
+            })
+            .collect::<Result<Vec<Instruction>, ParseError>>()?;
+
+        Ok(Supplies {
+            crate_stacks,
+            instructions,
+        })
+    }
+
+    fn part_1(&self, input: &Supplies) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Supplies) -> Answer {
+        part_2(input).into()
+    }
+}
+
+fn top_crates(crate_stacks: &[Vec<Crate>]) -> String {
+    String::from_iter(
+        crate_stacks
+            .iter()
+            .map(|s| {
+                if let Some(last) = s.last() {
+                    last.tag
+                } else {
+                    '\0'
+                }
+            })
+            .collect::<Vec<char>>(),
+    )
+}
+
+// Lifts the top `move_n` crates off the `from_stack`, in the order they end
+// up in on the `to_stack`
+fn lift(crate_stacks: &mut [Vec<Crate>], instr: &Instruction) -> Vec<Crate> {
+    let from = &mut crate_stacks[instr.from_stack - 1];
+    from.split_off(from.len().saturating_sub(instr.move_n))
+}
+
+pub fn part_1(supplies: &Supplies) -> String {
+    let mut crate_stacks = supplies.crate_stacks.clone();
+
+    supplies.instructions.iter().for_each(|instr| {
+        // One crate at a time, so the moved crates end up upside down
+        let mut moved = lift(&mut crate_stacks, instr);
+        moved.reverse();
+        crate_stacks[instr.to_stack - 1].extend(moved);
+    });
+
+    top_crates(&crate_stacks)
+}
+
+pub fn part_2(supplies: &Supplies) -> String {
+    let mut crate_stacks = supplies.crate_stacks.clone();
+
+    supplies.instructions.iter().for_each(|instr| {
+        let moved = lift(&mut crate_stacks, instr);
+        crate_stacks[instr.to_stack - 1].extend(moved);
+    });
+
+    top_crates(&crate_stacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_instr_test() {
+        let line = parse::lines(5, "move 1 from 2 to 3").next().unwrap();
+        assert_eq!(
+            Instruction::parse_instr(&line).unwrap(),
+            Instruction {
+                move_n: 1,
+                from_stack: 2,
+                to_stack: 3
+            }
+        );
+    }
+
+    #[test]
+    fn parse_instr_error_test() {
+        let line = parse::lines(5, "move 1 from x to 3").next().unwrap();
+        let err = Instruction::parse_instr(&line).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+    }
+
+    fn parse_error(input: &str) -> ParseError {
+        match Day05.parse(input) {
+            Ok(_) => panic!("{:?} parsed", input),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn impossible_move_test() {
+        let crates = "[A]    \n[B] [C]\n 1   2 \n\n";
+
+        // Stack 1 holds three crates once the first move is done
+        let err = parse_error(&format!(
+            "{}move 1 from 2 to 1\nmove 4 from 1 to 2\n",
+            crates
+        ));
+        assert_eq!((err.line, err.column), (6, 6));
+        assert!(err.message.contains("which holds 3"), "{}", err);
+
+        let err = parse_error(&format!("{}move -1 from 1 to 2\n", crates));
+        assert_eq!((err.line, err.column), (5, 6));
+
+        let err = parse_error(&format!("{}move 1 from 2 to 2\n", crates));
+        assert_eq!(err.line, 5);
+    }
+
+    #[test]
+    fn parse_crates_test() {
+        let line = "[A] [B] [C]";
+        assert_eq!(
+            Crate::parse_crate_line(line),
+            vec![Crate { tag: 'A' }, Crate { tag: 'B' }, Crate { tag: 'C' }]
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn instruction_round_trip(move_n in 0..100usize, from_stack in 1..10usize, to_stack in 1..10usize) {
+            let instr = Instruction { move_n, from_stack, to_stack };
+            let text = instr.to_string();
+            let line = parse::lines(5, &text).next().unwrap();
+            prop_assert_eq!(Instruction::parse_instr(&line).unwrap(), instr);
+        }
+
+        #[test]
+        fn crate_line_round_trip(tags in "[A-Z]{1,9}") {
+            let crates: Vec<Crate> = tags.chars().map(|tag| Crate { tag }).collect();
+            let line = crates.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" ");
+            prop_assert_eq!(Crate::parse_crate_line(&line), crates);
+        }
+    }
+}
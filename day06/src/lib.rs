@@ -0,0 +1,139 @@
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    // The datastream buffer
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String, ParseError> {
+        let input = &parse::normalize(input);
+        let line = parse::non_blank_lines(6, input)
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(6, input, "expected a datastream"))?;
+        let datastream = line.text.trim();
+
+        if let Some((i, c)) = datastream
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            return Err(line.error_at(&datastream[i..], format!("invalid character '{}'", c)));
+        }
+        Ok(datastream.to_string())
+    }
+
+    fn part_1(&self, input: &String) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &String) -> Answer {
+        part_2(input).into()
+    }
+}
+
+// Index just after the first 4 distinct characters in a row
+pub fn detect_marker(input: &str) -> i32 {
+    let input_iterator = input.chars();
+    let found_at_index: Vec<i32> = input_iterator
+        .enumerate()
+        .map(|(i, _)| {
+            if i + 4 <= input.len() {
+                let mut set: HashSet<u8> = HashSet::new();
+
+                if set.insert(*input.as_bytes().get(i).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 1).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 2).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 3).unwrap())
+                {
+                    i as i32
+                } else {
+                    -1
+                }
+            } else {
+                -1
+            }
+        })
+        .collect();
+
+    for i in found_at_index {
+        if i != -1 {
+            return i + 4;
+        }
+    }
+    0
+}
+
+pub fn part_1(input: &str) -> i32 {
+    detect_marker(input)
+}
+
+pub fn part_2(input: &str) -> i32 {
+    let input_iterator = input.chars();
+    let found_at_index: Vec<i32> = input_iterator
+        .enumerate()
+        .map(|(i, _)| {
+            if i + 14 <= input.len() {
+                let mut set: HashSet<u8> = HashSet::new();
+
+                // oogabooga
+                if set.insert(*input.as_bytes().get(i).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 1).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 2).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 3).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 4).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 5).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 6).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 7).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 8).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 9).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 10).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 11).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 12).unwrap())
+                    && set.insert(*input.as_bytes().get(i + 13).unwrap())
+                {
+                    i as i32
+                } else {
+                    -1
+                }
+            } else {
+                -1
+            }
+        })
+        .collect();
+
+    for i in found_at_index {
+        if i != -1 {
+            return i + 14;
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_instr_test() {
+        let datastream = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(detect_marker(datastream), 7);
+
+        let datastream = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(detect_marker(datastream), 5);
+
+        let datastream = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(detect_marker(datastream), 6);
+
+        let datastream = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(detect_marker(datastream), 10);
+
+        let datastream = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(detect_marker(datastream), 11);
+
+        // Markers ending on the last character
+        assert_eq!(detect_marker("aaabcd"), 6);
+        assert_eq!(part_2("aabcdefghijklmn"), 15);
+    }
+}
@@ -0,0 +1,193 @@
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Dir {
+    pub name: String,
+    pub files: HashMap<String, File>,
+    pub dirs: HashMap<String, Dir>,
+}
+
+impl Dir {
+    pub fn get_files_size(&self) -> u32 {
+        self.files.values().map(|file| file.size).sum()
+    }
+
+    pub fn get_total_size(&self) -> u32 {
+        let mut size = self.get_files_size();
+        get_dirs_in_dir(self)
+            .iter()
+            .for_each(|d| size += d.get_files_size());
+        size
+    }
+}
+
+pub struct File {
+    pub name: String,
+    pub size: u32,
+}
+
+enum CommandLine {
+    Ls,
+    Cd(String),
+    File(File),
+    Dir(Dir),
+}
+
+// The root directory, keyed by its name `/`
+pub struct FileSystem {
+    pub dirs: HashMap<String, Dir>,
+}
+
+// Every directory below `dir`, however deep
+pub fn get_dirs_in_dir(dir: &Dir) -> Vec<&Dir> {
+    let mut dirs: Vec<&Dir> = Vec::new();
+
+    for d in dir.dirs.values() {
+        dirs.push(d);
+        dirs.append(&mut get_dirs_in_dir(d))
+    }
+
+    dirs
+}
+
+// Directory at the path `pwd`, if every directory on the way has been listed
+fn working_dir<'a>(fs: &'a mut FileSystem, pwd: &[String]) -> Option<&'a mut Dir> {
+    let mut curdir = fs.dirs.get_mut(pwd.first()?)?;
+
+    for directory in pwd.iter().skip(1) {
+        curdir = curdir.dirs.get_mut(directory)?
+    }
+    Some(curdir)
+}
+
+fn parse_filesystem(input: &str) -> Result<FileSystem, ParseError> {
+    let mut fs = FileSystem {
+        dirs: HashMap::new(),
+    };
+    let mut pwd: Vec<String> = Vec::new();
+
+    let root: Dir = Dir {
+        name: "/".to_string(),
+        files: HashMap::new(),
+        dirs: HashMap::new(),
+    };
+    fs.dirs.insert(root.name.to_string(), root);
+
+    for line in parse::non_blank_lines(7, input) {
+        let text = line.text.trim_end();
+        let cmd = if let Some(dir) = text.strip_prefix("$ cd ") {
+            CommandLine::Cd(dir.to_string())
+        } else if text == "$ ls" {
+            CommandLine::Ls // Nothing to do here!
+        } else if let Some(name) = text.strip_prefix("dir ") {
+            CommandLine::Dir(Dir {
+                name: name.to_string(),
+                files: HashMap::new(),
+                dirs: HashMap::new(),
+            })
+        } else if text.starts_with('$') {
+            return Err(line.error(1, format!("unknown command '{}'", text)));
+        } else {
+            let (size, name) = text
+                .split_once(" ")
+                .ok_or_else(|| line.error(1, "expected a file size and name"))?;
+            CommandLine::File(File {
+                size: line.parse(size, "file size")?,
+                name: name.to_string(),
+            })
+        };
+
+        match cmd {
+            CommandLine::Cd(dir) => {
+                if dir == "/" {
+                    pwd = vec![dir];
+                } else if dir == ".." {
+                    if pwd.len() < 2 {
+                        return Err(line.error_at(&text[5..], "can't leave the root directory"));
+                    }
+                    pwd.pop();
+                } else {
+                    pwd.push(dir);
+                    if working_dir(&mut fs, &pwd).is_none() {
+                        return Err(line.error_at(&text[5..], "directory hasn't been listed"));
+                    }
+                }
+            }
+            CommandLine::File(file) => {
+                let curdir = working_dir(&mut fs, &pwd)
+                    .ok_or_else(|| line.error(1, "listing before 'cd /'"))?;
+                curdir.files.insert(file.name.to_string(), file);
+            }
+            CommandLine::Dir(dir) => {
+                let curdir = working_dir(&mut fs, &pwd)
+                    .ok_or_else(|| line.error(1, "listing before 'cd /'"))?;
+                curdir.dirs.insert(dir.name.to_string(), dir);
+            }
+            CommandLine::Ls => (),
+        }
+    }
+    Ok(fs)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = FileSystem;
+
+    fn parse(&self, input: &str) -> Result<FileSystem, ParseError> {
+        let input = &parse::normalize(input);
+        parse_filesystem(input)
+    }
+
+    fn part_1(&self, input: &FileSystem) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &FileSystem) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(fs: &FileSystem) -> u32 {
+    get_dirs_in_dir(&fs.dirs["/"])
+        .iter()
+        .map(|dir| dir.get_total_size())
+        .filter(|&v| v < 100000)
+        .sum()
+}
+
+pub fn part_2(fs: &FileSystem) -> u32 {
+    const TOTAL_DISK_SPACE: u32 = 70000000;
+    const FREE_DISK_SPACE: u32 = 30000000;
+
+    let root = &fs.dirs["/"];
+    let used = root.get_total_size();
+    // A listing bigger than the disk leaves no free space at all
+    let free_disk_space = TOTAL_DISK_SPACE.saturating_sub(used);
+
+    // Deleting everything always frees enough, so start from the root
+    get_dirs_in_dir(root)
+        .iter()
+        .map(|dir| dir.get_total_size())
+        .filter(|&size| size + free_disk_space >= FREE_DISK_SPACE)
+        .fold(used, u32::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_disk_test() {
+        // More than the whole disk, in a file next to a directory
+        let input = "$ cd /\n$ ls\ndir a\n80000000 b.txt\n$ cd a\n$ ls\n5 c.txt\n";
+        let fs = Day07.parse(input).unwrap();
+        assert_eq!(part_2(&fs), 80000005);
+
+        // Too little to free by deleting a directory below the root
+        let input = "$ cd /\n$ ls\ndir a\n50000000 b.txt\n$ cd a\n$ ls\n5 c.txt\n";
+        let fs = Day07.parse(input).unwrap();
+        assert_eq!(part_2(&fs), 50000005);
+    }
+}
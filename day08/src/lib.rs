@@ -0,0 +1,285 @@
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Pos};
+use std::cmp;
+use std::fmt;
+use visual::Rgb;
+
+pub struct Tree {
+    pub val: u32,
+    pub score: u32,
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.val, self.score)
+    }
+}
+
+impl PartialEq for Tree {
+    fn eq(&self, other: &Self) -> bool {
+        self.val == other.val
+    }
+}
+
+impl PartialOrd for Tree {
+    fn gt(&self, other: &Self) -> bool {
+        self.val > other.val
+    }
+    fn lt(&self, other: &Self) -> bool {
+        self.val < other.val
+    }
+
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.val.partial_cmp(&other.val)
+    }
+}
+
+// TreeVisibleness is a real word
+pub enum TreeVisibleness {
+    Unidentified(Tree),
+    Visible(Tree),
+    NotVisible(Tree),
+}
+
+impl TreeVisibleness {
+    pub fn get(&self) -> &Tree {
+        match self {
+            TreeVisibleness::Unidentified(t) => t,
+            TreeVisibleness::Visible(t) => t,
+            TreeVisibleness::NotVisible(t) => t,
+        }
+    }
+
+    pub fn to_visible(&self) -> TreeVisibleness {
+        match self {
+            TreeVisibleness::Unidentified(t) => TreeVisibleness::Visible(Tree {
+                val: t.val,
+                score: t.score,
+            }),
+            TreeVisibleness::Visible(t) => TreeVisibleness::Visible(Tree {
+                val: t.val,
+                score: t.score,
+            }),
+            TreeVisibleness::NotVisible(t) => TreeVisibleness::Visible(Tree {
+                val: t.val,
+                score: t.score,
+            }),
+        }
+    }
+
+    pub fn to_not_visible(&self) -> TreeVisibleness {
+        match self {
+            TreeVisibleness::Unidentified(t) => TreeVisibleness::NotVisible(Tree {
+                val: t.val,
+                score: t.score,
+            }),
+            TreeVisibleness::Visible(t) => TreeVisibleness::NotVisible(Tree {
+                val: t.val,
+                score: t.score,
+            }),
+            TreeVisibleness::NotVisible(t) => TreeVisibleness::NotVisible(Tree {
+                val: t.val,
+                score: t.score,
+            }),
+        }
+    }
+
+    pub fn set_score(&self, score: u32) -> TreeVisibleness {
+        match self {
+            TreeVisibleness::Unidentified(t) => {
+                TreeVisibleness::NotVisible(Tree { val: t.val, score })
+            }
+            TreeVisibleness::Visible(t) => TreeVisibleness::NotVisible(Tree { val: t.val, score }),
+            TreeVisibleness::NotVisible(t) => {
+                TreeVisibleness::NotVisible(Tree { val: t.val, score })
+            }
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        match self {
+            TreeVisibleness::Unidentified(_) => false,
+            TreeVisibleness::Visible(_) => true,
+            TreeVisibleness::NotVisible(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for TreeVisibleness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TreeVisibleness::Unidentified(t) => write!(f, "U: {}", t),
+            TreeVisibleness::Visible(t) => write!(f, "V: {}", t),
+            TreeVisibleness::NotVisible(t) => write!(f, "N: {}", t),
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    // Tree heights
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Grid<u32>, ParseError> {
+        let input = &parse::normalize(input);
+        Grid::parse(8, input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| format!("invalid tree height '{}'", c))
+        })
+    }
+
+    fn part_1(&self, input: &Grid<u32>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Grid<u32>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn unidentified_trees(heights: &Grid<u32>) -> Grid<TreeVisibleness> {
+    heights.map(|&val| {
+        TreeVisibleness::Unidentified(Tree {
+            val,
+            score: 0, // only used in part 2
+        })
+    })
+}
+
+pub fn part_1(heights: &Grid<u32>) -> u32 {
+    let (width, height) = (heights.width(), heights.height());
+    let mut m = unidentified_trees(heights);
+
+    for y in 0..height {
+        for x in 0..width {
+            let pos = Pos::new(x, y);
+
+            // All the outer trees are visible
+            if x == 0 || y == 0 || x == width - 1 || y == height - 1 {
+                m[pos] = m[pos].to_visible();
+                continue;
+            }
+
+            m[pos] = m[pos].to_visible();
+
+            for h in y + 1..height {
+                if m[pos].get() <= m[Pos::new(x, h)].get() {
+                    m[pos] = m[pos].to_not_visible();
+                    break;
+                }
+            }
+
+            if m[pos].is_visible() {
+                continue;
+            }
+
+            m[pos] = m[pos].to_visible();
+
+            for h in (0..y).rev() {
+                if m[pos].get() <= m[Pos::new(x, h)].get() {
+                    m[pos] = m[pos].to_not_visible();
+                    break;
+                }
+            }
+
+            if m[pos].is_visible() {
+                continue;
+            }
+            m[pos] = m[pos].to_visible();
+
+            for v in x + 1..width {
+                if m[pos].get() <= m[Pos::new(v, y)].get() {
+                    m[pos] = m[pos].to_not_visible();
+                    break;
+                }
+            }
+
+            if m[pos].is_visible() {
+                continue;
+            }
+
+            m[pos] = m[pos].to_visible();
+
+            for v in (0..x).rev() {
+                if m[pos].get() <= m[Pos::new(v, y)].get() {
+                    m[pos] = m[pos].to_not_visible();
+                    break;
+                }
+            }
+        }
+    }
+    let visible_tree_count = m.iter().filter(|(_, e)| e.is_visible()).count();
+    visible_tree_count as u32
+}
+
+// Scenic score of every tree
+pub fn scenic_scores(heights: &Grid<u32>) -> Grid<u32> {
+    let (width, height) = (heights.width(), heights.height());
+    let mut m = unidentified_trees(heights);
+
+    for y in 0..height {
+        for x in 0..width {
+            let pos = Pos::new(x, y);
+            let mut left = 0;
+            let mut right = 0;
+            let mut up = 0;
+            let mut down = 0;
+
+            for h in y + 1..height {
+                down += 1;
+                if m[pos].get() <= m[Pos::new(x, h)].get() {
+                    break;
+                }
+            }
+
+            for h in (0..y).rev() {
+                up += 1;
+                if m[pos].get() <= m[Pos::new(x, h)].get() {
+                    break;
+                }
+            }
+
+            for v in x + 1..width {
+                right += 1;
+                if m[pos].get() <= m[Pos::new(v, y)].get() {
+                    break;
+                }
+            }
+
+            for v in (0..x).rev() {
+                left += 1;
+                if m[pos].get() <= m[Pos::new(v, y)].get() {
+                    break;
+                }
+            }
+            m[pos] = m[pos].set_score(left * right * up * down);
+        }
+    }
+    m.map(|e| e.get().score)
+}
+
+pub fn part_2(heights: &Grid<u32>) -> u32 {
+    *scenic_scores(heights)
+        .iter()
+        .map(|(_, score)| score)
+        .max()
+        .unwrap()
+}
+
+// Heatmap of the scenic scores, brightest where the view is best
+pub fn scenic_image(heights: &Grid<u32>) -> Grid<Rgb> {
+    let scores = scenic_scores(heights);
+    let best = *scores.iter().map(|(_, score)| score).max().unwrap_or(&0);
+
+    // A few trees score far higher than the rest, so brighten the low end
+    scores.map(|&score| {
+        let t = if best == 0 {
+            0.0
+        } else {
+            score as f64 / best as f64
+        };
+        Rgb::BLACK.mix(Rgb::YELLOW, t.sqrt())
+    })
+}
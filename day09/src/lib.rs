@@ -0,0 +1,315 @@
+use common::parse::{self, Line};
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point, Pos};
+use std::collections::HashSet;
+use std::fmt;
+use visual::{Canvas, Frame, Rgb};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub dir: Direction,
+    pub steps: i32,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.dir, self.steps)
+    }
+}
+
+impl Move {
+    pub fn parse(line: &Line) -> Result<Move, ParseError> {
+        let (dir, steps) = line
+            .text
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| line.error(1, "expected a direction and a step count"))?;
+
+        let dir = match dir {
+            "U" => Direction::Up,
+            "L" => Direction::Left,
+            "D" => Direction::Down,
+            "R" => Direction::Right,
+            _ => return Err(line.error_at(dir, format!("invalid direction '{}'", dir))),
+        };
+
+        Ok(Move {
+            dir,
+            steps: line.parse(steps, "steps")?,
+        })
+    }
+}
+
+// Where a knot ends up after the knot ahead of it has moved.
+// It only moves when they're no longer touching, one step straight
+// or diagonally towards the knot ahead
+pub fn follow(ahead: Point, knot: Point) -> Point {
+    if ahead.chebyshev(knot) >= 2 {
+        knot + (ahead - knot).signum()
+    } else {
+        knot
+    }
+}
+
+pub struct Rope {
+    pub knots: Vec<Point>, // head first
+}
+
+impl Rope {
+    pub fn new(tail_count: usize) -> Rope {
+        Rope {
+            knots: vec![Point::ORIGIN; tail_count + 1],
+        }
+    }
+
+    pub fn step(&mut self, dir: Direction) {
+        self.knots[0] += dir.step();
+        for i in 1..self.knots.len() {
+            self.knots[i] = follow(self.knots[i - 1], self.knots[i]);
+        }
+    }
+
+    pub fn tail(&self) -> Point {
+        self.knots[self.knots.len() - 1]
+    }
+}
+
+// Places visited by the last tail
+fn visited(moves: &[Move], tail_count: usize) -> HashSet<Point> {
+    let mut rope = Rope::new(tail_count);
+    let mut visited: HashSet<Point> = HashSet::from([rope.tail()]);
+
+    for move_instr in moves {
+        for _ in 0..move_instr.steps {
+            rope.step(move_instr.dir);
+            visited.insert(rope.tail());
+        }
+    }
+
+    visited
+}
+
+// Unique places visited by the last tail
+pub fn move_rope(moves: &[Move], tail_count: usize) -> i32 {
+    visited(moves, tail_count).len() as i32
+}
+
+// Size of the window on the plane when animating
+const VIEW_WIDTH: i32 = 80;
+const VIEW_HEIGHT: i32 = 30;
+const VIEW_MARGIN: i32 = 5;
+
+// Shifts the view by half its size when the head gets close to an edge
+fn scroll_view(view: Point, head: Point) -> Point {
+    let scroll = |start: i32, pos: i32, size: i32| {
+        if pos < start + VIEW_MARGIN {
+            start - size / 2
+        } else if pos >= start + size - VIEW_MARGIN {
+            start + size / 2
+        } else {
+            start
+        }
+    };
+    Point::new(
+        scroll(view.x, head.x, VIEW_WIDTH),
+        scroll(view.y, head.y, VIEW_HEIGHT),
+    )
+}
+
+// Draws the knots, the places the tail visited and the start, with the
+// view's top left corner at `view`
+fn render(rope: &Rope, visited: &HashSet<Point>, view: Point) -> Vec<String> {
+    (view.y..view.y + VIEW_HEIGHT)
+        .map(|y| {
+            (view.x..view.x + VIEW_WIDTH)
+                .map(|x| {
+                    let point = Point::new(x, y);
+                    match rope.knots.iter().position(|&knot| knot == point) {
+                        Some(0) => 'H',
+                        Some(_) if rope.knots.len() == 2 => 'T',
+                        Some(i) => char::from_digit(i as u32, 10).unwrap_or('*'),
+                        None if point == Point::ORIGIN => 's',
+                        None if visited.contains(&point) => '#',
+                        None => '.',
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Shows the rope after every step
+pub fn animate(moves: &[Move], tail_count: usize, canvas: &mut impl Canvas) {
+    let mut rope = Rope::new(tail_count);
+    let mut visited: HashSet<Point> = HashSet::from([rope.tail()]);
+    let mut view = Point::new(-VIEW_WIDTH / 2, -VIEW_HEIGHT / 2);
+
+    for (i, move_instr) in moves.iter().enumerate() {
+        for _ in 0..move_instr.steps {
+            rope.step(move_instr.dir);
+            visited.insert(rope.tail());
+            view = scroll_view(view, rope.knots[0]);
+
+            let frame = Frame {
+                rows: render(&rope, &visited, view),
+                caption: format!(
+                    "move {}/{}: {}, {} visited",
+                    i + 1,
+                    moves.len(),
+                    move_instr,
+                    visited.len()
+                ),
+            };
+            if canvas.show(&frame).is_break() {
+                return;
+            }
+        }
+    }
+}
+
+// Places visited by the last tail in white, the start in red
+pub fn visited_image(moves: &[Move], tail_count: usize) -> Grid<Rgb> {
+    let visited = visited(moves, tail_count);
+
+    let top_left = Point::new(
+        visited.iter().map(|p| p.x).min().unwrap(),
+        visited.iter().map(|p| p.y).min().unwrap(),
+    );
+    let bottom_right = Point::new(
+        visited.iter().map(|p| p.x).max().unwrap(),
+        visited.iter().map(|p| p.y).max().unwrap(),
+    );
+    let size = bottom_right - top_left + Point::new(1, 1);
+
+    let mut image = Grid::new(size.x as usize, size.y as usize, Rgb::BLACK);
+    let to_pos = |p: Point| Pos::try_from(p - top_left).unwrap();
+    for &point in &visited {
+        image[to_pos(point)] = Rgb::WHITE;
+    }
+    image[to_pos(Point::ORIGIN)] = Rgb::RED;
+    image
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Move>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Move>, ParseError> {
+        let input = &parse::normalize(input);
+        parse::non_blank_lines(9, input)
+            .map(|line| Move::parse(&line))
+            .collect()
+    }
+
+    fn part_1(&self, input: &Vec<Move>) -> Answer {
+        move_rope(input, 1).into()
+    }
+
+    fn part_2(&self, input: &Vec<Move>) -> Answer {
+        move_rope(input, 9).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example() {
+        let tail_count: usize = 1;
+        let str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"
+        .to_string();
+
+        assert_eq!(move_rope(&Day09.parse(&str).unwrap(), tail_count), 13);
+    }
+
+    #[test]
+    fn test_example_2() {
+        let tail_count: usize = 9;
+        let str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+    "
+        .to_string();
+
+        assert_eq!(move_rope(&Day09.parse(&str).unwrap(), tail_count), 1);
+    }
+
+    #[test]
+    fn test_example_3() {
+        let tail_count: usize = 9;
+        let str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+    "
+        .to_string();
+
+        assert_eq!(move_rope(&Day09.parse(&str).unwrap(), tail_count), 36);
+    }
+
+    #[test]
+    fn test_animate() {
+        let moves = Day09
+            .parse("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n")
+            .unwrap();
+        let mut recorder = visual::Recorder::default();
+        animate(&moves, 1, &mut recorder);
+
+        assert_eq!(recorder.frames.len(), 24);
+        let last = recorder.frames.last().unwrap();
+        assert_eq!(last.caption, "move 8/8: R 2, 13 visited");
+        assert_eq!(last.rows[13], format!("{:.<40}{:.<40}", "", ".TH##"));
+    }
+
+    #[test]
+    fn test_invalid_direction() {
+        let err = Day09.parse("R 4\nX 2\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    fn any_move() -> impl Strategy<Value = Move> {
+        (prop::sample::select(Direction::ALL.to_vec()), 1..20i32)
+            .prop_map(|(dir, steps)| Move { dir, steps })
+    }
+
+    proptest! {
+        #[test]
+        fn move_round_trip(moves in prop::collection::vec(any_move(), 1..20)) {
+            let text: String = moves.iter().map(|m| format!("{}\n", m)).collect();
+            prop_assert_eq!(Day09.parse(&text).unwrap(), moves);
+        }
+
+        #[test]
+        fn knots_stay_touching(moves in prop::collection::vec(any_move(), 1..50), tail_count in 1..10usize) {
+            let mut rope = Rope::new(tail_count);
+            for m in &moves {
+                for _ in 0..m.steps {
+                    rope.step(m.dir);
+                    for pair in rope.knots.windows(2) {
+                        prop_assert!(pair[0].chebyshev(pair[1]) <= 1, "{} and {} apart", pair[0], pair[1]);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Pos};
+use std::fmt;
+use std::ops::ControlFlow;
+use visual::{Canvas, Frame, Rgb};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Addx(x) => write!(f, "addx {}", x),
+            Instruction::Noop => write!(f, "noop"),
+        }
+    }
+}
+
+pub struct SimpleCPU {
+    pub x: i32,
+    pub cycle: i32,
+    pub sig_str: i32,
+}
+
+impl SimpleCPU {
+    pub fn new() -> SimpleCPU {
+        SimpleCPU {
+            x: 1,
+            cycle: 1,
+            sig_str: 0,
+        }
+    }
+
+    pub fn clock(&mut self) {
+        self.cycle += 1;
+    }
+
+    pub fn eval_instr(&mut self, instr: Instruction) {
+        match instr {
+            Instruction::Addx(x) => self.x += x,
+            Instruction::Noop => (),
+        }
+    }
+
+    pub fn incr_sig_str(&mut self) {
+        if self.cycle == 20 || (self.cycle + 20) % 40 == 0 {
+            self.sig_str += self.cycle * self.x;
+        }
+    }
+}
+
+impl Default for SimpleCPU {
+    fn default() -> SimpleCPU {
+        SimpleCPU::new()
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct CRT {
+    pub width: i32,
+    pub height: i32,
+    pub curr_row: Vec<char>,
+    pub sprite_pos: i32,
+}
+
+impl CRT {
+    pub fn new() -> CRT {
+        CRT {
+            width: 40,
+            height: 6,
+            curr_row: Vec::new(),
+            sprite_pos: 1,
+        }
+    }
+
+    pub fn update_screen(&mut self, cpu_cycle: i32) {
+        // Cycles count from 1, pixels from 0
+        let pixel = (cpu_cycle - 1) % self.width;
+
+        if (pixel - self.sprite_pos).abs() <= 1 {
+            self.curr_row.push('#');
+        } else {
+            self.curr_row.push('.');
+        }
+    }
+
+    // The pixels drawn so far, one line per row
+    pub fn screen(&self) -> String {
+        self.curr_row
+            .chunks(self.width as usize)
+            .take(self.height as usize)
+            .map(|c| c.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl Default for CRT {
+    fn default() -> CRT {
+        CRT::new()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, ParseError> {
+        let input = &parse::normalize(input);
+        parse::non_blank_lines(10, input)
+            .map(|line| {
+                let text = line.text.trim();
+                if text == "noop" {
+                    Ok(Instruction::Noop)
+                } else if let Some(v) = text.strip_prefix("addx ") {
+                    Ok(Instruction::Addx(line.parse(v, "addx value")?))
+                } else {
+                    Err(line.error(1, format!("unknown instruction '{}'", text)))
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(&self, input: &Vec<Instruction>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Vec<Instruction>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(program: &[Instruction]) -> i32 {
+    let mut cpu: SimpleCPU = SimpleCPU::new();
+
+    program.iter().for_each(|&n| {
+        match n {
+            Instruction::Addx(_) => {
+                cpu.incr_sig_str();
+                cpu.clock();
+                cpu.incr_sig_str();
+                cpu.clock();
+            }
+            Instruction::Noop => {
+                cpu.incr_sig_str();
+                cpu.clock();
+            }
+        }
+        cpu.eval_instr(n);
+    });
+    cpu.sig_str
+}
+
+// Runs the program on the CRT, drawing a pixel every cycle. `on_pixel` is
+// called after each pixel and can stop the program early
+fn run_crt(
+    program: &[Instruction],
+    mut on_pixel: impl FnMut(&SimpleCPU, &CRT) -> ControlFlow<()>,
+) -> CRT {
+    let mut cpu: SimpleCPU = SimpleCPU::new();
+    let mut crt: CRT = CRT::new();
+
+    for &n in program {
+        let cycles = match n {
+            Instruction::Addx(_) => 2,
+            Instruction::Noop => 1,
+        };
+        for _ in 0..cycles {
+            crt.update_screen(cpu.cycle);
+            if on_pixel(&cpu, &crt).is_break() {
+                return crt;
+            }
+            cpu.clock();
+        }
+        cpu.eval_instr(n);
+        crt.sprite_pos = cpu.x; // set sprite position
+    }
+    crt
+}
+
+// Returns the CRT screen, one line per row of pixels
+pub fn part_2(program: &[Instruction]) -> String {
+    run_crt(program, |_, _| ControlFlow::Continue(())).screen()
+}
+
+// The CRT pixels, lit in green
+pub fn screen_image(program: &[Instruction]) -> Grid<Rgb> {
+    let crt = run_crt(program, |_, _| ControlFlow::Continue(()));
+    let (width, height) = (crt.width as usize, crt.height as usize);
+
+    let mut image = Grid::new(width, height, Rgb::BLACK);
+    for (i, &pixel) in crt.curr_row.iter().take(width * height).enumerate() {
+        if pixel == '#' {
+            image[Pos::new(i % width, i / width)] = Rgb::GREEN;
+        }
+    }
+    image
+}
+
+// Shows the screen after every cycle, with the sprite under it
+pub fn animate(program: &[Instruction], canvas: &mut impl Canvas) {
+    run_crt(program, |cpu, crt| {
+        let mut rows: Vec<String> = crt.screen().lines().map(String::from).collect();
+        rows.resize(crt.height as usize + 1, String::new());
+        rows.push(
+            (0..crt.width)
+                .map(|x| {
+                    if (x - crt.sprite_pos).abs() <= 1 {
+                        '#'
+                    } else {
+                        ' '
+                    }
+                })
+                .collect(),
+        );
+
+        canvas.show(&Frame {
+            rows,
+            caption: format!("cycle {}, X = {}", cpu.cycle, cpu.x),
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn any_instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            Just(Instruction::Noop),
+            (-50..50i32).prop_map(Instruction::Addx),
+        ]
+    }
+
+    #[test]
+    fn animate_test() {
+        let program = Day10.parse("noop\naddx 3\naddx -5\n").unwrap();
+        let mut recorder = visual::Recorder::default();
+        animate(&program, &mut recorder);
+
+        assert_eq!(recorder.frames.len(), 5);
+        let last = recorder.frames.last().unwrap();
+        assert_eq!(last.rows[0], "#####");
+        assert_eq!(last.rows[7].trim_end(), "   ###");
+        assert_eq!(last.caption, "cycle 5, X = 4");
+    }
+
+    proptest! {
+        #[test]
+        fn instruction_round_trip(program in prop::collection::vec(any_instruction(), 1..50)) {
+            let text: String = program.iter().map(|instr| format!("{}\n", instr)).collect();
+            prop_assert_eq!(Day10.parse(&text).unwrap(), program);
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use common::parse::{self, Line};
+use common::{Answer, ParseError, Solution};
+use std::collections::LinkedList;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Arg {
+    Old,
+    Number(u64),
+}
+
+impl Arg {
+    fn parse(line: &Line, str: &str) -> Result<Arg, ParseError> {
+        match str {
+            "old" => Ok(Arg::Old),
+            _ => Ok(Arg::Number(line.parse(str, "operand")?)),
+        }
+    }
+}
+
+// Operation: new = old * old
+// new = old .. part is always the same
+// so we only have to pick operator and last arg
+#[derive(Debug, PartialEq, Clone)]
+pub enum Operation {
+    Add(Arg),
+    Mul(Arg),
+}
+
+impl Operation {
+    fn parse(line: &Line, str: &str) -> Result<Operation, ParseError> {
+        let (op, val) = str
+            .trim()
+            .split_once(" ")
+            .ok_or_else(|| line.error_at(str, "expected an operator and an operand"))?;
+
+        match op {
+            "*" => Ok(Operation::Mul(Arg::parse(line, val)?)),
+            "+" => Ok(Operation::Add(Arg::parse(line, val)?)),
+            _ => Err(line.error_at(op, format!("unknown operator '{}'", op))),
+        }
+    }
+
+    pub fn exec(&self, arg: u64) -> u64 {
+        match self {
+            Operation::Add(x) => match x {
+                Arg::Number(x) => arg + x,
+                Arg::Old => arg + arg,
+            },
+            Operation::Mul(x) => match x {
+                Arg::Number(x) => arg * x,
+                Arg::Old => arg * arg,
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    pub items: LinkedList<u64>,
+    pub operation: Operation,
+    pub divisible: u64,
+    pub true_index: i32,
+    pub false_index: i32,
+    pub inspection_amount: u64,
+}
+
+impl Monkey {
+    fn new() -> Monkey {
+        Monkey {
+            items: LinkedList::new(),
+            operation: Operation::Add(Arg::Old),
+            divisible: 0,
+            true_index: 0,
+            false_index: 0,
+            inspection_amount: 0,
+        }
+    }
+
+    // `monkey_count` is used to check that thrown items land on a monkey
+    fn from_lines(lines: &[Line], monkey_count: usize) -> Result<Monkey, ParseError> {
+        let mut monkey = Monkey::new();
+        let mut seen = [false; 5];
+
+        for line in lines {
+            let text = line.text.trim();
+
+            if text.starts_with("Monkey ") {
+                // Monkeys are numbered by their position
+            } else if let Some(items) = text.strip_prefix("Starting items:") {
+                monkey.items = items
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| line.parse(item, "worry level"))
+                    .collect::<Result<LinkedList<u64>, ParseError>>()?;
+                seen[0] = true;
+            } else if let Some(op) = text.strip_prefix("Operation: new = old ") {
+                monkey.operation = Operation::parse(line, op)?;
+                seen[1] = true;
+            } else if let Some(divisible) = text.strip_prefix("Test: divisible by ") {
+                monkey.divisible = line.parse(divisible.trim(), "divisor")?;
+                if monkey.divisible == 0 {
+                    return Err(line.error_at(divisible, "divisor can't be zero"));
+                }
+                seen[2] = true;
+            } else if let Some(index) = text.strip_prefix("If true: throw to monkey ") {
+                monkey.true_index = parse_target(line, index.trim(), monkey_count)?;
+                seen[3] = true;
+            } else if let Some(index) = text.strip_prefix("If false: throw to monkey ") {
+                monkey.false_index = parse_target(line, index.trim(), monkey_count)?;
+                seen[4] = true;
+            } else {
+                return Err(line.error(1, format!("unexpected line '{}'", text)));
+            }
+        }
+
+        const FIELDS: [&str; 5] = ["Starting items", "Operation", "Test", "If true", "If false"];
+        if let Some(i) = seen.iter().position(|&seen| !seen) {
+            return Err(lines[0].error(1, format!("monkey is missing '{}'", FIELDS[i])));
+        }
+        Ok(monkey)
+    }
+}
+
+fn parse_target(line: &Line, index: &str, monkey_count: usize) -> Result<i32, ParseError> {
+    let target = line.parse(index, "monkey")?;
+    if target < 0 || target as usize >= monkey_count {
+        return Err(line.error_at(index, format!("there is no monkey {} to throw to", target)));
+    }
+    Ok(target)
+}
+
+fn parse_monkeys_from_str(str: &str) -> Result<Vec<Monkey>, ParseError> {
+    let blocks = parse::blocks(11, str);
+    if blocks.len() < 2 {
+        return Err(ParseError::end_of_input(
+            11,
+            str,
+            "expected at least two monkeys",
+        ));
+    }
+
+    blocks
+        .iter()
+        .map(|lines| Monkey::from_lines(lines, blocks.len()))
+        .collect()
+}
+
+// Plays `rounds` rounds and returns the monkey business: the product of the
+// two highest inspection counts
+pub fn start_monkey_inspection<T>(monkeys: &mut [Monkey], rounds: i32, worry_mod: T) -> u64
+where
+    T: Fn(u64) -> u64,
+{
+    let monkey_amount = monkeys.len();
+    for _ in 0..rounds {
+        for n in 0..monkey_amount {
+            let mut monkey = &mut monkeys[n];
+            let item_count = monkey.items.len();
+
+            for _ in 0..item_count {
+                monkey = &mut monkeys[n];
+                monkey.inspection_amount += 1;
+                let item = monkey.items.pop_front().unwrap();
+
+                let mut worry_level = monkey.operation.exec(item);
+                worry_level = worry_mod(worry_level);
+
+                let monkey_index = if worry_level.is_multiple_of(monkey.divisible) {
+                    monkey.true_index as usize
+                } else {
+                    monkey.false_index as usize
+                };
+                monkey = &mut monkeys[monkey_index];
+                monkey.items.push_back(worry_level);
+            }
+        }
+    }
+
+    let mut res: Vec<u64> = monkeys.iter().map(|e| e.inspection_amount).collect();
+    res.sort_unstable();
+    res.reverse();
+    res[0] * res[1]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Monkey>, ParseError> {
+        let input = &parse::normalize(input);
+        parse_monkeys_from_str(input)
+    }
+
+    fn part_1(&self, input: &Vec<Monkey>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Vec<Monkey>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    start_monkey_inspection(monkeys.as_mut(), 20, |modify: u64| modify / 3)
+}
+
+pub fn part_2(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let product = monkeys.iter().map(|m| m.divisible).product::<u64>();
+    start_monkey_inspection(monkeys.as_mut(), 10000, |modify: u64| modify % product)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_create_monkey_from_string() {
+        let str = "Monkey 0:
+Starting items: 50, 70, 54, 83, 52, 78
+Operation: new = old * 3
+Test: divisible by 11
+    If true: throw to monkey 2
+    If false: throw to monkey 7
+
+"
+        .to_string();
+
+        let lines: Vec<Line> = parse::non_blank_lines(11, &str).collect();
+        let monkey = Monkey::from_lines(&lines, 8).unwrap();
+
+        assert_eq!(monkey.items, LinkedList::from([50, 70, 54, 83, 52, 78]));
+        assert_eq!(monkey.operation, Operation::Mul(Arg::Number(3)));
+        assert_eq!(monkey.divisible, 11);
+        assert_eq!(monkey.true_index, 2);
+        assert_eq!(monkey.false_index, 7);
+    }
+
+    #[test]
+    fn test_unknown_operator() {
+        let lines: Vec<Line> = parse::lines(11, "Operation: new = old / 3").collect();
+        let err = Monkey::from_lines(&lines, 8).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 22));
+    }
+}
@@ -0,0 +1,189 @@
+extern crate pathfinding;
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Pos};
+use pathfinding::prelude::dijkstra;
+use visual::Rgb;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd)]
+pub struct Successor {
+    pub pos: Pos,
+    pub cost: i32,
+}
+
+impl PartialEq<(Pos, i32)> for Successor {
+    fn eq(&self, other: &(Pos, i32)) -> bool {
+        self.pos == other.0 && self.cost == other.1
+    }
+}
+
+pub struct Map {
+    pub data: Grid<Option<(u8, u8)>>,
+    pub start: Vec<Pos>,
+    pub goal: Pos,
+}
+
+impl Map {
+    pub fn new(squares: &Grid<char>, start_char: Vec<char>) -> Map {
+        let mut start: Vec<Pos> = Vec::new();
+        let mut goal = Pos::default();
+
+        let mut data = squares.map(|&c| Some((1, c as u8)));
+
+        for (pos, &c) in squares.iter() {
+            if start_char.contains(&c) {
+                start.push(pos);
+                data[pos] = Some((1, b'a'));
+            } else if c == 'E' {
+                goal = pos;
+                data[pos] = Some((1, b'z'));
+            }
+        }
+        Map { data, start, goal }
+    }
+
+    pub fn get_successors(&self, position: &Pos) -> Vec<Successor> {
+        let mut successors = Vec::new();
+
+        for new_pos in self.data.neighbors_4(*position) {
+            // Diff in elevation
+            let mut delev = 0;
+
+            let val = self.data[*position];
+            if let Some((_, elev)) = val {
+                delev = elev;
+            }
+
+            let val = self.data[new_pos];
+            if let Some((cost, elev)) = val {
+                // Remove when elevation diff is too great
+                if delev < elev && delev.abs_diff(elev) > 1 {
+                    continue;
+                }
+
+                successors.push(Successor {
+                    pos: new_pos,
+                    cost: cost as i32,
+                });
+            }
+        }
+
+        successors
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    // Heightmap squares, turned into a `Map` once each part knows its start squares
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>, ParseError> {
+        let input = &parse::normalize(input);
+        let squares = Grid::parse(12, input, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c),
+            _ => Err(format!("invalid square '{}'", c)),
+        })?;
+
+        let count = |square: char| squares.iter().filter(|&(_, &c)| c == square).count();
+        let (starts, goals) = (count('S'), count('E'));
+        if starts != 1 || goals != 1 {
+            return Err(ParseError::end_of_input(
+                12,
+                input,
+                format!(
+                    "expected one start and one goal, found {} and {}",
+                    starts, goals
+                ),
+            ));
+        }
+
+        let map = Map::new(&squares, vec!['S']);
+        if shortest_path(&map, map.start[0]).is_none() {
+            let line = parse::lines(12, input).nth(map.goal.y).unwrap();
+            return Err(line.error(map.goal.x + 1, "the goal can't be reached from the start"));
+        }
+        Ok(squares)
+    }
+
+    fn part_1(&self, input: &Grid<char>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Grid<char>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+// Shortest path from `start` to the goal and its length, if there is one
+fn shortest_path(map: &Map, start: Pos) -> Option<(Vec<Pos>, i32)> {
+    dijkstra(
+        &start,
+        |p| {
+            map.get_successors(p)
+                .iter()
+                .map(|s| (s.pos, s.cost))
+                .collect::<Vec<_>>()
+        },
+        |p| *p == map.goal,
+    )
+}
+
+// Shortest path to the goal from any of the map's start squares
+fn best_path(map: &Map) -> Option<(Vec<Pos>, i32)> {
+    map.start
+        .iter()
+        .filter_map(|&start| shortest_path(map, start))
+        .min_by_key(|(_, steps)| *steps)
+}
+
+pub fn part_1(squares: &Grid<char>) -> i32 {
+    let map = Map::new(squares, vec!['S']);
+    best_path(&map)
+        .expect("parse checks the goal can be reached")
+        .1
+}
+
+pub fn part_2(squares: &Grid<char>) -> i32 {
+    let map = Map::new(squares, vec!['S', 'a']);
+    best_path(&map)
+        .expect("parse checks the goal can be reached")
+        .1
+}
+
+// Elevation from dark to light green, with the shortest path in red.
+// Part 2 starts from the best of the lowest squares
+pub fn route_image(squares: &Grid<char>, any_low_start: bool) -> Grid<Rgb> {
+    let start_chars = if any_low_start {
+        vec!['S', 'a']
+    } else {
+        vec!['S']
+    };
+    let map = Map::new(squares, start_chars);
+
+    let mut image = map.data.map(|square| {
+        let elevation = square.map_or(0, |(_, elevation)| elevation - b'a');
+        Rgb(10, 40, 20).mix(Rgb(200, 240, 200), elevation as f64 / 25.0)
+    });
+    if let Some((path, _)) = best_path(&map) {
+        for pos in path {
+            image[pos] = Rgb::RED;
+        }
+    }
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreachable_goal_test() {
+        // The goal sits on a peak two steps above everything around it
+        let err = Day12.parse("Sac\nabE\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let squares = Day12.parse("SbcdefghijklmnopqrstuvwxyE\n").unwrap();
+        assert_eq!(part_1(&squares), 25);
+    }
+}
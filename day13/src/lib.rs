@@ -0,0 +1,225 @@
+use common::parse::{self, Line};
+use common::{Answer, ParseError, Solution};
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum PacketData {
+    List(Vec<PacketData>),
+    Integer(isize),
+}
+
+// Prints packets the way they appear in the puzzle input
+impl fmt::Display for PacketData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PacketData::Integer(x) => write!(f, "{}", x),
+            PacketData::List(list) => {
+                write!(f, "[")?;
+                for (i, item) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+// Packets are in the right order when `compare` gives `Less`.
+// Integers are compared as one-element lists when matched against a list
+pub fn compare(left: &PacketData, right: &PacketData) -> Ordering {
+    match (left, right) {
+        (PacketData::Integer(x), PacketData::Integer(y)) => x.cmp(y),
+        (PacketData::Integer(x), PacketData::List(_)) => {
+            compare(&PacketData::List(vec![PacketData::Integer(*x)]), right)
+        }
+        (PacketData::List(_), PacketData::Integer(y)) => {
+            compare(left, &PacketData::List(vec![PacketData::Integer(*y)]))
+        }
+        (PacketData::List(x), PacketData::List(y)) => {
+            for (l, r) in x.iter().zip(y.iter()) {
+                let order = compare(l, r);
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+
+            // All items in common are equal, so the shorter list goes first
+            x.len().cmp(&y.len())
+        }
+    }
+}
+
+impl FromStr for PacketData {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<PacketData, serde_json::Error> {
+        serde_json::from_str(s.trim())
+    }
+}
+
+impl PartialOrd for PacketData {
+    fn partial_cmp(&self, other: &PacketData) -> Option<Ordering> {
+        Some(compare(self, other))
+    }
+}
+
+fn parse_packet(line: &Line) -> Result<PacketData, ParseError> {
+    line.text
+        .parse::<PacketData>()
+        .map_err(|e| line.error(e.column().max(1), format!("invalid packet: {}", e)))
+}
+
+fn parse_packet_pair(pair: &[Line]) -> Result<Vec<PacketData>, ParseError> {
+    if pair.len() != 2 {
+        return Err(pair[0].error(
+            1,
+            format!("expected a pair of packets, found {}", pair.len()),
+        ));
+    }
+    pair.iter().map(parse_packet).collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    // Packets grouped by pair
+    type Input = Vec<Vec<PacketData>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<PacketData>>, ParseError> {
+        let input = &parse::normalize(input);
+        parse::blocks(13, input)
+            .iter()
+            .map(|pair| parse_packet_pair(pair))
+            .collect()
+    }
+
+    fn part_1(&self, input: &Vec<Vec<PacketData>>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Vec<Vec<PacketData>>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(pairs: &[Vec<PacketData>]) -> usize {
+    let mut index = vec![];
+
+    for (i, pair_vec) in pairs.iter().enumerate() {
+        if pair_vec[0] < pair_vec[1] {
+            index.push(i + 1);
+        }
+    }
+
+    index.iter().sum()
+}
+
+pub fn part_2(pairs: &[Vec<PacketData>]) -> usize {
+    let div_packet1 = PacketData::List(vec![PacketData::List(vec![PacketData::Integer(6)])]);
+    let div_packet2 = PacketData::List(vec![PacketData::List(vec![PacketData::Integer(2)])]);
+    let mut packets: Vec<PacketData> = vec![div_packet1.clone(), div_packet2.clone()];
+
+    for pair in pairs {
+        packets.extend(pair.iter().cloned());
+    }
+
+    packets.sort_by(compare);
+
+    let mut div: Vec<usize> = vec![];
+
+    for (i, e) in packets.iter().enumerate() {
+        if e.eq(&div_packet1) || e.eq(&div_packet2) {
+            div.push(i + 1);
+        }
+    }
+    div.iter().product()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_create_array() {
+        let str = "[1,[2,[3,[4,[5,6,7]]]],8,9]";
+
+        let actual = parse_packet(&parse::lines(13, str).next().unwrap()).unwrap();
+
+        let expected = PacketData::List(vec![
+            PacketData::Integer(1),
+            PacketData::List(vec![
+                PacketData::Integer(2),
+                PacketData::List(vec![
+                    PacketData::Integer(3),
+                    PacketData::List(vec![
+                        PacketData::Integer(4),
+                        PacketData::List(vec![
+                            PacketData::Integer(5),
+                            PacketData::Integer(6),
+                            PacketData::Integer(7),
+                        ]),
+                    ]),
+                ]),
+            ]),
+            PacketData::Integer(8),
+            PacketData::Integer(9),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+    #[test]
+    fn test_longer_left_list_is_greater() {
+        let pair = Day13.parse("[1,2]\n[1]\n").unwrap().remove(0);
+        let (left, right) = (&pair[0], &pair[1]);
+
+        assert_eq!(compare(left, right), Ordering::Greater);
+        assert_eq!(compare(right, left), Ordering::Less);
+    }
+
+    #[test]
+    fn test_unpaired_packet() {
+        let err = Day13.parse("[1]\n[2]\n\n[3]\n").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+
+    fn any_packet() -> impl Strategy<Value = PacketData> {
+        let leaf = (0..11isize).prop_map(PacketData::Integer);
+        leaf.prop_recursive(4, 32, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(PacketData::List)
+        })
+    }
+
+    fn any_list() -> impl Strategy<Value = PacketData> {
+        prop::collection::vec(any_packet(), 0..5).prop_map(PacketData::List)
+    }
+
+    proptest! {
+        #[test]
+        fn packet_round_trip(packet in any_list()) {
+            let text = packet.to_string();
+            let line = parse::lines(13, &text).next().unwrap();
+            prop_assert_eq!(parse_packet(&line).unwrap(), packet);
+        }
+
+        // Packets that compare equal may still differ, e.g. [[1]] and [1],
+        // so the order is total over those equivalence classes
+        #[test]
+        fn packets_are_totally_ordered(a in any_packet(), b in any_packet(), c in any_packet()) {
+            let ab = a.partial_cmp(&b);
+            prop_assert!(ab.is_some());
+            prop_assert_eq!(a.partial_cmp(&a), Some(Ordering::Equal));
+            prop_assert_eq!(b.partial_cmp(&a), ab.map(Ordering::reverse));
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point, Pos};
+use std::fmt;
+use visual::{Canvas, Frame, Rgb};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Unit {
+    SandSource,
+    Sand,
+    Rock,
+    Air,
+}
+
+impl Unit {
+    pub fn symbol(&self) -> char {
+        match self {
+            Unit::SandSource => '+',
+            Unit::Sand => 'o',
+            Unit::Rock => '#',
+            Unit::Air => ' ',
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+pub struct Simulation {
+    pub scan_map: Grid<Unit>,
+    pub sand_source: Pos,
+}
+
+impl Simulation {
+    pub fn from_paths(rock_paths: &[Vec<Point>], infinite: bool) -> Simulation {
+        const SAND_SOURCE: Point = Point { x: 500, y: 0 };
+
+        let points = || rock_paths.iter().flatten().chain([&SAND_SOURCE]);
+        let x_min = points().map(|p| p.x).min().unwrap();
+        let x_max = points().map(|p| p.x).max().unwrap();
+        let y_min = points().map(|p| p.y).min().unwrap();
+        let y_max = points().map(|p| p.y).max().unwrap();
+
+        // The floor is two below the lowest rock. Sand piles up at most as
+        // far to the sides as it falls, so leave that much room around the rocks
+        let floor = if infinite { y_max + 2 } else { y_max };
+        let margin = floor - y_min + 1;
+        let top_left = Point::new(x_min - margin, y_min);
+        let to_pos = |p: Point| Pos::try_from(p - top_left).unwrap();
+
+        let scan_width = (x_max + margin - top_left.x + 1) as usize;
+        let scan_height = (floor - y_min + 1) as usize;
+
+        let mut sim = Simulation {
+            scan_map: Grid::new(scan_width, scan_height, Unit::Air),
+            sand_source: to_pos(SAND_SOURCE),
+        };
+
+        for path in rock_paths {
+            for coord in path.windows(2) {
+                let (from, to) = (coord[0], coord[1]);
+                let step = (to - from).signum();
+
+                let mut rock = from;
+                sim.scan_map[to_pos(rock)] = Unit::Rock;
+                while rock != to {
+                    rock += step;
+                    sim.scan_map[to_pos(rock)] = Unit::Rock;
+                }
+            }
+        }
+
+        if infinite {
+            for x in 0..scan_width {
+                sim.scan_map[Pos::new(x, scan_height - 1)] = Unit::Rock;
+            }
+        }
+
+        sim.scan_map[sim.sand_source] = Unit::SandSource;
+        sim
+    }
+
+    // Drops one grain of sand from the source.
+    // Returns where it comes to rest, or `None` if it falls out of the map
+    // or the source is already covered.
+    pub fn drop_sand(&mut self) -> Option<Pos> {
+        if self.scan_map[self.sand_source] == Unit::Sand {
+            return None;
+        }
+
+        let mut sand = self.sand_source;
+        loop {
+            if sand.y + 1 == self.scan_map.height() {
+                return None;
+            }
+
+            // Straight down, then down to the left, then down to the right
+            let next = [sand.x, sand.x.wrapping_sub(1), sand.x + 1]
+                .into_iter()
+                .map(|x| Pos::new(x, sand.y + 1))
+                .find(|&pos| !matches!(self.scan_map.get(pos), Some(Unit::Rock | Unit::Sand)));
+
+            match next {
+                Some(pos) if !self.scan_map.contains(pos) => return None,
+                Some(pos) => sand = pos,
+                None => {
+                    self.scan_map[sand] = Unit::Sand;
+                    return Some(sand);
+                }
+            }
+        }
+    }
+
+    // Drops sand until it stops coming to rest.
+    // Returns how many grains came to rest
+    pub fn simulate(&mut self) -> usize {
+        let mut rested = 0;
+        while self.drop_sand().is_some() {
+            rested += 1;
+        }
+        rested
+    }
+
+    pub fn render(&self) -> String {
+        self.scan_map.render(Unit::symbol)
+    }
+}
+
+// The cave once the sand has settled: rock in grey, sand in yellow
+pub fn sand_image(rock_paths: &[Vec<Point>], infinite: bool) -> Grid<Rgb> {
+    let mut sim = Simulation::from_paths(rock_paths, infinite);
+    sim.simulate();
+    sim.scan_map.map(|unit| match unit {
+        Unit::SandSource => Rgb::RED,
+        Unit::Sand => Rgb::YELLOW,
+        Unit::Rock => Rgb::GREY,
+        Unit::Air => Rgb::BLACK,
+    })
+}
+
+// Shows the cave each time a grain of sand comes to rest
+pub fn animate(rock_paths: &[Vec<Point>], infinite: bool, canvas: &mut impl Canvas) {
+    let mut sim = Simulation::from_paths(rock_paths, infinite);
+    let mut rested = 0;
+
+    while sim.drop_sand().is_some() {
+        rested += 1;
+        let frame = Frame::new(&sim.render()).with_caption(format!("{} grains of sand", rested));
+        if canvas.show(&frame).is_break() {
+            return;
+        }
+    }
+}
+
+fn parse_rock_paths(input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    let paths = parse::non_blank_lines(14, input)
+        .map(|line| {
+            let mut path: Vec<Point> = Vec::new();
+
+            for cord in line.text.trim().split(" -> ") {
+                let (x, y) = cord.split_once(",").ok_or_else(|| {
+                    line.error_at(
+                        cord,
+                        format!("expected a coordinate like 498,4, found '{}'", cord),
+                    )
+                })?;
+                let point = Point::new(line.parse(x, "x")?, line.parse(y, "y")?);
+
+                // Rock is only laid out in horizontal or vertical lines
+                if let Some(&last) = path.last() {
+                    if last.x != point.x && last.y != point.y {
+                        return Err(line.error_at(cord, "diagonal rock path"));
+                    }
+                }
+                path.push(point);
+            }
+            Ok(path)
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    if paths.is_empty() {
+        return Err(ParseError::end_of_input(14, input, "expected a rock path"));
+    }
+    Ok(paths)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<Point>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+        let input = &parse::normalize(input);
+        parse_rock_paths(input)
+    }
+
+    fn part_1(&self, input: &Vec<Vec<Point>>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(&self, input: &Vec<Vec<Point>>) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn part_1(rock_paths: &[Vec<Point>]) -> usize {
+    Simulation::from_paths(rock_paths, false).simulate()
+}
+
+pub fn part_2(rock_paths: &[Vec<Point>]) -> usize {
+    Simulation::from_paths(rock_paths, true).simulate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Rock paths alternating between horizontal and vertical lines
+    fn any_path() -> impl Strategy<Value = Vec<Point>> {
+        (
+            (480..520i32, 1..30i32),
+            prop::collection::vec(-10..10i32, 1..6),
+        )
+            .prop_map(|((x, y), steps)| {
+                let mut path = vec![Point::new(x, y)];
+                for (i, step) in steps.into_iter().enumerate() {
+                    let last = path[path.len() - 1];
+                    let next = if i % 2 == 0 {
+                        Point::new(last.x + step, last.y)
+                    } else {
+                        Point::new(last.x, (last.y + step).max(1))
+                    };
+                    path.push(next);
+                }
+                path
+            })
+    }
+
+    fn print_paths(paths: &[Vec<Point>]) -> String {
+        paths
+            .iter()
+            .map(|path| {
+                let points: Vec<String> = path.iter().map(Point::to_string).collect();
+                format!("{}\n", points.join(" -> "))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn animate_test() {
+        let paths = Day14
+            .parse("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n")
+            .unwrap();
+        let mut recorder = visual::Recorder::default();
+        animate(&paths, false, &mut recorder);
+
+        assert_eq!(recorder.frames.len(), 24);
+        assert_eq!(recorder.frames[23].caption, "24 grains of sand");
+    }
+
+    proptest! {
+        #[test]
+        fn rock_paths_round_trip(paths in prop::collection::vec(any_path(), 1..5)) {
+            prop_assert_eq!(parse_rock_paths(&print_paths(&paths)).unwrap(), paths);
+        }
+
+        // Sand that rested without a floor rests the same way with one
+        #[test]
+        fn floor_holds_at_least_as_much_sand(paths in prop::collection::vec(any_path(), 1..5)) {
+            prop_assert!(part_2(&paths) >= part_1(&paths));
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use common::parse;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point, Pos};
+use regex::Regex;
+use std::collections::HashSet;
+use visual::Rgb;
+
+#[derive(Debug, Clone)]
+pub struct Sensor {
+    pub pos: Point,
+    pub beacon: Point,
+    // Manhattan distance from the sensor to its beacon
+    pub m_distance: i32,
+}
+
+impl Sensor {
+    fn new() -> Sensor {
+        Sensor {
+            pos: Point::ORIGIN,
+            beacon: Point::ORIGIN,
+            m_distance: 0,
+        }
+    }
+}
+
+fn parse_sensors(str: &str) -> Result<Vec<Sensor>, ParseError> {
+    let re =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
+            .unwrap();
+
+    parse::non_blank_lines(15, str)
+        .map(|line| {
+            let cap = re.captures(line.text.trim()).ok_or_else(|| {
+                line.error(
+                    1,
+                    "expected 'Sensor at x=X, y=Y: closest beacon is at x=X, y=Y'",
+                )
+            })?;
+            let coord = |i: usize| line.parse::<i32>(cap.get(i).unwrap().as_str(), "coordinate");
+
+            let mut sensor = Sensor::new();
+            let (a, b, c, d) = (coord(1)?, coord(2)?, coord(3)?, coord(4)?);
+            sensor.pos = Point::new(a, b);
+            sensor.beacon = Point::new(c, d);
+            sensor.m_distance = sensor.pos.manhattan(sensor.beacon);
+            Ok(sensor)
+        })
+        .collect()
+}
+
+// Count positions in row `y` where a beacon cannot be present
+pub fn no_beacon_count(sensors: &[Sensor], y: i32) -> usize {
+    let mut range_combined: Vec<i32> = vec![];
+    let mut beacon_row_set: HashSet<Point> = HashSet::new();
+
+    for sensor in sensors {
+        let Point { x: a, y: b } = sensor.pos;
+        let Point { x: c, y: d } = sensor.beacon;
+        let m = sensor.m_distance;
+
+        let y_range = b - m..=b + m;
+
+        if y_range.contains(&y) {
+            let y_offset = y.abs_diff(b) as i32;
+            let x_offset = a - m + y_offset;
+            let dist = 2 * (m - y_offset);
+            let range = x_offset..=x_offset + dist;
+
+            if d == y && range.contains(&c) {
+                beacon_row_set.insert(sensor.beacon);
+            }
+
+            range_combined.extend(range);
+        }
+    }
+    range_combined.sort_unstable();
+    range_combined.dedup();
+    range_combined.len() - beacon_row_set.len()
+}
+
+// The one position with 0 <= x, y <= limit that no sensor covers. It lies
+// just outside some sensors' ranges, so it is a corner of the area or a
+// point where the lines running one step outside the ranges cross each
+// other or an edge of the area
+pub fn distress_beacon(sensors: &[Sensor], limit: i32) -> Option<Point> {
+    // Lines x + y = c and x - y = d around every sensor
+    let (mut sums, mut diffs) = (vec![], vec![]);
+    for sensor in sensors {
+        let Point { x, y } = sensor.pos;
+        let r = sensor.m_distance + 1;
+        sums.extend([x + y - r, x + y + r]);
+        diffs.extend([x - y - r, x - y + r]);
+    }
+
+    let mut candidates = vec![
+        Point::new(0, 0),
+        Point::new(limit, 0),
+        Point::new(0, limit),
+        Point::new(limit, limit),
+    ];
+    for edge in [0, limit] {
+        for &c in &sums {
+            candidates.extend([Point::new(edge, c - edge), Point::new(c - edge, edge)]);
+        }
+        for &d in &diffs {
+            candidates.extend([Point::new(edge, edge - d), Point::new(d + edge, edge)]);
+        }
+    }
+    for &c in &sums {
+        for &d in &diffs {
+            // Lines of different parity cross between positions
+            let (x, y) = ((c + d).div_euclid(2), (c - d).div_euclid(2));
+            candidates.extend(
+                [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(dx, dy)| Point::new(x + dx, y + dy)),
+            );
+        }
+    }
+
+    candidates.into_iter().find(|p| {
+        (0..=limit).contains(&p.x)
+            && (0..=limit).contains(&p.y)
+            && sensors.iter().all(|s| s.pos.manhattan(*p) > s.m_distance)
+    })
+}
+
+// x * 4000000 + y of the distress beacon
+pub fn tuning_frequency(beacon: Point) -> i64 {
+    beacon.x as i64 * 4000000 + beacon.y as i64
+}
+
+// Where sensors rule out beacons, in blue, with the sensors in red and
+// their beacons in white. The longer side of the image is `size` pixels
+pub fn coverage_image(sensors: &[Sensor], size: usize) -> Grid<Rgb> {
+    let x_min = sensors
+        .iter()
+        .map(|s| s.pos.x - s.m_distance)
+        .min()
+        .unwrap_or(0);
+    let x_max = sensors
+        .iter()
+        .map(|s| s.pos.x + s.m_distance)
+        .max()
+        .unwrap_or(0);
+    let y_min = sensors
+        .iter()
+        .map(|s| s.pos.y - s.m_distance)
+        .min()
+        .unwrap_or(0);
+    let y_max = sensors
+        .iter()
+        .map(|s| s.pos.y + s.m_distance)
+        .max()
+        .unwrap_or(0);
+
+    // Each pixel covers a square of `cell` by `cell` positions
+    let span = (x_max - x_min).max(y_max - y_min) as f64 + 1.0;
+    let cell = span / size.max(1) as f64;
+    let width = ((x_max - x_min + 1) as f64 / cell).ceil() as usize;
+    let height = ((y_max - y_min + 1) as f64 / cell).ceil() as usize;
+    let to_point = |pos: Pos| {
+        Point::new(
+            x_min + (pos.x as f64 * cell) as i32,
+            y_min + (pos.y as f64 * cell) as i32,
+        )
+    };
+    let to_pos = |p: Point| {
+        Pos::new(
+            ((p.x - x_min) as f64 / cell) as usize,
+            ((p.y - y_min) as f64 / cell) as usize,
+        )
+    };
+
+    let mut image = Grid::new(width, height, Rgb::BLACK);
+    for pos in image.positions() {
+        let point = to_point(pos);
+        if sensors
+            .iter()
+            .any(|s| s.pos.manhattan(point) <= s.m_distance)
+        {
+            image[pos] = Rgb::BLUE;
+        }
+    }
+    for sensor in sensors {
+        if let Some(pixel) = image.get_mut(to_pos(sensor.beacon)) {
+            *pixel = Rgb::WHITE;
+        }
+        if let Some(pixel) = image.get_mut(to_pos(sensor.pos)) {
+            *pixel = Rgb::RED;
+        }
+    }
+    image
+}
+
+pub struct Day15 {
+    // Row checked for beacon-free positions in part 1
+    pub row: i32,
+    // Largest x and y searched for the distress beacon in part 2
+    pub limit: i32,
+}
+
+impl Default for Day15 {
+    fn default() -> Day15 {
+        Day15 {
+            row: 2000000,
+            limit: 4000000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+
+    // 2: part 2 finds the distress beacon instead of answering 0
+    const VERSION: u32 = 2;
+
+    fn parse(&self, input: &str) -> Result<Vec<Sensor>, ParseError> {
+        let input = &parse::normalize(input);
+        parse_sensors(input)
+    }
+
+    fn part_1(&self, input: &Vec<Sensor>) -> Answer {
+        no_beacon_count(input, self.row).into()
+    }
+
+    fn part_2(&self, input: &Vec<Sensor>) -> Answer {
+        let beacon = distress_beacon(input, self.limit)
+            .expect("every position in the area is covered by a sensor");
+        tuning_frequency(beacon).into()
+    }
+}
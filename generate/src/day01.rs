@@ -0,0 +1,15 @@
+use rand::Rng;
+
+// `size` elves carrying 1 to 10 snacks each, at least three elves
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            let snacks = rng.gen_range(1..=10);
+            (0..snacks)
+                .map(|_| format!("{}\n", rng.gen_range(1..=60000)))
+                .collect()
+        })
+        .collect();
+
+    elves.join("\n")
+}
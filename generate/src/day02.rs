@@ -0,0 +1,12 @@
+use rand::Rng;
+
+// `size` rounds of the strategy guide
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let elf = ['A', 'B', 'C'][rng.gen_range(0..3)];
+            let human = ['X', 'Y', 'Z'][rng.gen_range(0..3)];
+            format!("{} {}\n", elf, human)
+        })
+        .collect()
+}
@@ -0,0 +1,35 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// `size` groups of three rucksacks. The compartments of a rucksack share
+// exactly one item and the rucksacks of a group share exactly one badge
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(rng);
+        let badge = items[0];
+
+        // Each rucksack draws from its own 17 items so only the badge is shared
+        for own in items[1..].chunks(17) {
+            let (shared, rest) = (own[0], &own[1..]);
+            let (left_items, right_items) = rest.split_at(rest.len() / 2);
+            let len = rng.gen_range(2..=16);
+
+            let mut left = vec![shared];
+            left.extend((1..len).map(|_| *left_items.choose(rng).unwrap()));
+            let mut right = vec![shared, badge];
+            right.extend((2..len).map(|_| *right_items.choose(rng).unwrap()));
+            left.shuffle(rng);
+            right.shuffle(rng);
+
+            input.push_str(std::str::from_utf8(&left).unwrap());
+            input.push_str(std::str::from_utf8(&right).unwrap());
+            input.push('\n');
+        }
+    }
+    input
+}
@@ -0,0 +1,17 @@
+use rand::Rng;
+
+fn range(rng: &mut impl Rng) -> (u32, u32) {
+    let start = rng.gen_range(1..=99);
+    (start, rng.gen_range(start..=99))
+}
+
+// `size` pairs of section ranges
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (a, b) = range(rng);
+            let (c, d) = range(rng);
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
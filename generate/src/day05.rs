@@ -0,0 +1,49 @@
+use rand::Rng;
+
+// Up to nine stacks of crates and `size` moves (at least one, as the parser
+// needs a procedure) that always leave at least one crate on every stack, so
+// every stack has a crate on top at the end
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let stack_count = rng.gen_range(3..=9);
+    let mut heights: Vec<usize> = (0..stack_count).map(|_| rng.gen_range(1..=8)).collect();
+    // With more crates than stacks there is always a stack to move from
+    if heights.iter().all(|&height| height == 1) {
+        heights[0] = 2;
+    }
+    let top = *heights.iter().max().unwrap();
+
+    let mut input = String::new();
+    for level in (0..top).rev() {
+        let row: Vec<String> = heights
+            .iter()
+            .map(|&height| {
+                if level < height {
+                    format!("[{}]", rng.gen_range(b'A'..=b'Z') as char)
+                } else {
+                    "   ".to_string()
+                }
+            })
+            .collect();
+        input.push_str(&row.join(" "));
+        input.push('\n');
+    }
+
+    let labels: Vec<String> = (1..=stack_count).map(|i| format!(" {} ", i)).collect();
+    input.push_str(&labels.join(" "));
+    input.push_str("\n\n");
+
+    for _ in 0..size.max(1) {
+        let movable: Vec<usize> = (0..stack_count).filter(|&i| heights[i] > 1).collect();
+        let from = movable[rng.gen_range(0..movable.len())];
+        let mut to = rng.gen_range(0..stack_count - 1);
+        if to >= from {
+            to += 1;
+        }
+        let count = rng.gen_range(1..heights[from]);
+
+        heights[from] -= count;
+        heights[to] += count;
+        input.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+    }
+    input
+}
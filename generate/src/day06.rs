@@ -0,0 +1,16 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// A datastream of `size` characters, ending in a start-of-message marker
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(15);
+
+    // Few letters make markers rare until the end
+    let mut stream: Vec<u8> = (0..size - 14).map(|_| rng.gen_range(b'a'..=b'e')).collect();
+
+    let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+    marker.shuffle(rng);
+    stream.extend(&marker[..14]);
+
+    String::from_utf8(stream).unwrap() + "\n"
+}
@@ -0,0 +1,98 @@
+use rand::Rng;
+
+const TOTAL_DISK_SPACE: u32 = 70000000;
+const FREE_DISK_SPACE: u32 = 30000000;
+// Most files a directory holds
+const MAX_FILES: u32 = 4;
+
+struct Dir {
+    files: Vec<(String, u32)>,
+    dirs: Vec<(String, Dir)>,
+}
+
+impl Dir {
+    fn size(&self) -> u32 {
+        let files: u32 = self.files.iter().map(|(_, size)| size).sum();
+        files + self.dirs.iter().map(|(_, dir)| dir.size()).sum::<u32>()
+    }
+
+    fn transcript(&self, input: &mut String) {
+        input.push_str("$ ls\n");
+        for (name, _) in &self.dirs {
+            input.push_str(&format!("dir {}\n", name));
+        }
+        for (name, size) in &self.files {
+            input.push_str(&format!("{} {}\n", size, name));
+        }
+        for (name, dir) in &self.dirs {
+            input.push_str(&format!("$ cd {}\n", name));
+            dir.transcript(input);
+            input.push_str("$ cd ..\n");
+        }
+    }
+}
+
+fn name(rng: &mut impl Rng, i: usize) -> String {
+    let len = rng.gen_range(1..=6);
+    let letters: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+    format!("{}{}", letters, i)
+}
+
+fn files(rng: &mut impl Rng, max_size: u32) -> Vec<(String, u32)> {
+    (0..rng.gen_range(0..=MAX_FILES as usize))
+        .map(|i| (format!("{}.txt", name(rng, i)), rng.gen_range(1..=max_size)))
+        .collect()
+}
+
+// Builds a tree of `count` directories below `dir`, with files of at most
+// `max_size`
+fn grow(rng: &mut impl Rng, dir: &mut Dir, count: usize, max_size: u32) {
+    let mut left = count;
+    let mut i = 0;
+    while left > 0 {
+        let below = rng.gen_range(0..left);
+        let mut sub = Dir {
+            files: files(rng, max_size),
+            dirs: vec![],
+        };
+        grow(rng, &mut sub, below, max_size);
+        dir.dirs.push((name(rng, i), sub));
+        left -= below + 1;
+        i += 1;
+    }
+}
+
+// A terminal transcript of a disk with `size` directories, full enough that
+// part 2 has a directory to delete
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    // Small enough that the files of the root and all `size` directories fit
+    // in the least space used, so the padding never overfills the disk
+    let least_used = TOTAL_DISK_SPACE - FREE_DISK_SPACE;
+    let max_size = least_used / (MAX_FILES * (size as u32 + 1));
+
+    let mut disk = Dir {
+        dirs: vec![],
+        files: files(rng, max_size.clamp(1, 100000)),
+    };
+
+    // Everything but a few files at the root goes in one directory
+    let mut main = Dir {
+        files: vec![],
+        dirs: vec![],
+    };
+    grow(
+        rng,
+        &mut main,
+        size.saturating_sub(1),
+        max_size.clamp(1, 300000),
+    );
+
+    let used = rng.gen_range(TOTAL_DISK_SPACE - FREE_DISK_SPACE + 1..TOTAL_DISK_SPACE);
+    let missing = used.saturating_sub(disk.size() + main.size());
+    main.files.push(("padding.bin".to_string(), missing.max(1)));
+    disk.dirs.push((name(rng, 0), main));
+
+    let mut input = String::from("$ cd /\n");
+    disk.transcript(&mut input);
+    input
+}
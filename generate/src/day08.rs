@@ -0,0 +1,14 @@
+use rand::Rng;
+
+// A `size` by `size` grid of tree heights
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap())
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
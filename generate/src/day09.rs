@@ -0,0 +1,11 @@
+use rand::Rng;
+
+// `size` moves of the rope's head
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let dir = ['U', 'D', 'L', 'R'][rng.gen_range(0..4)];
+            format!("{} {}\n", dir, rng.gen_range(1..=20))
+        })
+        .collect()
+}
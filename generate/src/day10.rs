@@ -0,0 +1,32 @@
+use rand::Rng;
+
+// `size` instructions, padded with `noop` so the program draws the whole screen
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut cycles = 0;
+    let mut x: i32 = 1;
+
+    for _ in 0..size {
+        if rng.gen_bool(0.3) {
+            input.push_str("noop\n");
+            cycles += 1;
+        } else {
+            // Keep the sprite near the screen
+            let v = rng.gen_range(-10..=10);
+            let v = if (-5..45).contains(&(x + v)) {
+                v
+            } else {
+                -v.signum() * 5
+            };
+            x += v;
+            input.push_str(&format!("addx {}\n", v));
+            cycles += 2;
+        }
+    }
+
+    while cycles < 240 {
+        input.push_str("noop\n");
+        cycles += 1;
+    }
+    input
+}
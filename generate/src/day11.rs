@@ -0,0 +1,45 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const DIVISORS: [u64; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+
+// Notes on `size` monkeys, between 2 and 10. Only one monkey multiplies
+// and nobody squares, so part 1 worry levels fit in a u64
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let count = size.clamp(2, DIVISORS.len());
+    let mut divisors = DIVISORS;
+    divisors.shuffle(rng);
+    let multiplier = rng.gen_range(0..count);
+
+    let monkeys: Vec<String> = (0..count)
+        .map(|i| {
+            let items: Vec<String> = (0..rng.gen_range(0..=6))
+                .map(|_| rng.gen_range(50..100).to_string())
+                .collect();
+
+            let operation = if i == multiplier {
+                format!("* {}", rng.gen_range(2..=19))
+            } else if rng.gen_bool(0.2) {
+                "+ old".to_string()
+            } else {
+                format!("+ {}", rng.gen_range(1..=8))
+            };
+
+            let mut others: Vec<usize> = (0..count).filter(|&j| j != i).collect();
+            others.shuffle(rng);
+            let if_false = if others.len() > 1 { others[1] } else { others[0] };
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                i,
+                items.join(", "),
+                operation,
+                divisors[i],
+                others[0],
+                if_false
+            )
+        })
+        .collect();
+
+    monkeys.join("\n")
+}
@@ -0,0 +1,32 @@
+use rand::Rng;
+
+// A `size` by `size` heightmap (at least 14 squares wide, so the climb fits)
+// with a path from `S` to `E` that never climbs more than one step
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(14);
+    let mut map: Vec<Vec<u8>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_range(b'a'..=b'z')).collect())
+        .collect();
+
+    // Walk right and down from the top left corner to the bottom right one
+    let mut path = vec![(0, 0)];
+    let (mut x, mut y) = (0, 0);
+    while (x, y) != (size - 1, size - 1) {
+        if y == size - 1 || (x < size - 1 && rng.gen_bool(0.5)) {
+            x += 1;
+        } else {
+            y += 1;
+        }
+        path.push((x, y));
+    }
+
+    for (i, &(x, y)) in path.iter().enumerate() {
+        map[y][x] = b'a' + (i * 25 / (path.len() - 1)) as u8;
+    }
+    map[0][0] = b'S';
+    map[size - 1][size - 1] = b'E';
+
+    map.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
@@ -0,0 +1,22 @@
+use rand::Rng;
+
+fn packet(rng: &mut impl Rng, depth: u32) -> String {
+    let items: Vec<String> = (0..rng.gen_range(0..=4))
+        .map(|_| {
+            if depth < 4 && rng.gen_bool(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.gen_range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+// `size` pairs of packets
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size)
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .collect();
+    pairs.join("\n")
+}
@@ -0,0 +1,23 @@
+use rand::Rng;
+
+// `size` rock paths below the sand source at 500,0, and at least one so the
+// sand has something to land on
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let (mut x, mut y): (i32, i32) = (rng.gen_range(470..=530), rng.gen_range(2..=60));
+            let mut path = vec![format!("{},{}", x, y)];
+
+            // Alternate horizontal and vertical segments
+            for i in 0..rng.gen_range(1..=4) {
+                if i % 2 == 0 {
+                    x = (x + rng.gen_range(-6..=6)).max(0);
+                } else {
+                    y = (y + rng.gen_range(-6..=6)).max(1);
+                }
+                path.push(format!("{},{}", x, y));
+            }
+            path.join(" -> ") + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,18 @@
+use rand::Rng;
+
+// `size` sensors spread around the row checked in part 1
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (x, y): (i32, i32) = (rng.gen_range(0..=4000000), rng.gen_range(0..=4000000));
+            let (bx, by) = (
+                x + rng.gen_range(-500000..=500000),
+                y + rng.gen_range(-500000..=500000),
+            );
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                x, y, bx, by
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,64 @@
+//! Random puzzle inputs for stress testing the solutions. Every generator
+//! only produces input that its day's parser accepts.
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Generates an input for `day`. `size` scales the input (the number of
+/// elves, rounds, moves, the side of a grid and so on) and the same `seed`
+/// always gives the same input. Every size, including 0, gives an input the
+/// day's parser accepts. Returns `None` if there is no generator for that
+/// day.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+
+    let input = match day {
+        1 => day01::generate(rng, size),
+        2 => day02::generate(rng, size),
+        3 => day03::generate(rng, size),
+        4 => day04::generate(rng, size),
+        5 => day05::generate(rng, size),
+        6 => day06::generate(rng, size),
+        7 => day07::generate(rng, size),
+        8 => day08::generate(rng, size),
+        9 => day09::generate(rng, size),
+        10 => day10::generate(rng, size),
+        11 => day11::generate(rng, size),
+        12 => day12::generate(rng, size),
+        13 => day13::generate(rng, size),
+        14 => day14::generate(rng, size),
+        15 => day15::generate(rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_test() {
+        for day in 1..=15 {
+            assert_eq!(generate(day, 20, 7), generate(day, 20, 7), "day {}", day);
+            assert_ne!(generate(day, 20, 7), generate(day, 20, 8), "day {}", day);
+        }
+        assert_eq!(generate(16, 20, 7), None);
+    }
+}
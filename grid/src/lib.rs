@@ -0,0 +1,248 @@
+mod point;
+
+pub use point::{Direction, Point};
+
+use common::parse;
+use common::ParseError;
+use std::ops::{Index, IndexMut};
+
+/// Position of a cell. `x` counts columns and `y` counts rows, both from the
+/// top left corner.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+}
+
+const NEIGHBORS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per non-blank line of a day's input, turning every
+    /// character into a cell with `cell`. Its error message is reported at
+    /// the character's column.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in parse::non_blank_lines(day, input) {
+            let row = line.text.trim();
+            for (i, c) in row.char_indices() {
+                cells.push(cell(c).map_err(|message| line.error_at(&row[i..], message))?);
+            }
+
+            let row_width = row.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error_at(
+                        row,
+                        format!("expected {} cells in a row, found {}", width, row_width),
+                    ))
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            None => Err(ParseError::end_of_input(day, input, "expected a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| Pos::new(i % width, i / width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Cells of column `x`, from the top.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Positions next to `pos` that are inside the grid, not counting diagonals.
+    pub fn neighbors_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &NEIGHBORS_4)
+    }
+
+    /// Positions next to `pos` that are inside the grid, counting diagonals.
+    pub fn neighbors_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &NEIGHBORS_8)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        pos: Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbor = Pos::new(pos.x.checked_add_signed(dx)?, pos.y.checked_add_signed(dy)?);
+            self.contains(neighbor).then_some(neighbor)
+        })
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid with one character per cell and one line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.cells
+            .chunks(self.width)
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(0, input, |c| {
+            c.to_digit(10).ok_or(format!("invalid digit '{}'", c))
+        })
+    }
+
+    #[test]
+    fn parse_render_test() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 6);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(
+            grid.render(|d| char::from_digit(*d, 10).unwrap()),
+            "123\n456"
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = digits("123\n4x6\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = digits("123\n45\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbors_4(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors_8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors_4(Pos::new(0, 0)).collect::<Vec<_>>(),
+            vec![Pos::new(1, 0), Pos::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors_8(Pos::new(2, 2)).count(), 3);
+    }
+}
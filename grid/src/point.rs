@@ -0,0 +1,183 @@
+use crate::Pos;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point or vector on an unbounded plane. Like `Pos`, `y` grows downwards.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// Distance moving only along the axes.
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance moving along the axes and diagonals.
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Each coordinate replaced by -1, 0 or 1, turning a vector into a
+    /// single step in its direction.
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Each coordinate clamped to `min..=max`.
+    pub fn clamp(self, min: i32, max: i32) -> Point {
+        Point::new(self.x.clamp(min, max), self.y.clamp(min, max))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, n: i32) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl From<Pos> for Point {
+    fn from(pos: Pos) -> Point {
+        Point::new(pos.x as i32, pos.y as i32)
+    }
+}
+
+impl TryFrom<Point> for Pos {
+    type Error = Point;
+
+    /// Fails with the point itself if either coordinate is negative.
+    fn try_from(point: Point) -> Result<Pos, Point> {
+        match (usize::try_from(point.x), usize::try_from(point.y)) {
+            (Ok(x), Ok(y)) => Ok(Pos::new(x, y)),
+            _ => Err(point),
+        }
+    }
+}
+
+/// One of the four directions along the axes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// A single step in this direction.
+    pub fn step(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+// Written as the first letter, the way puzzle inputs spell directions
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Up => write!(f, "U"),
+            Direction::Down => write!(f, "D"),
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_test() {
+        let (a, b) = (Point::new(8, 7), Point::new(2, 10));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn direction_test() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.step() + dir.opposite().step(), Point::ORIGIN);
+        }
+        assert_eq!(Point::ORIGIN + Direction::Up.step() * 3, Point::new(0, -3));
+    }
+
+    #[test]
+    fn pos_test() {
+        assert_eq!(Pos::try_from(Point::new(3, 4)), Ok(Pos::new(3, 4)));
+        assert!(Pos::try_from(Point::new(-1, 4)).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use grid::{Grid, Pos};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// A colour with 8 bits a channel.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+    pub const GREEN: Rgb = Rgb(40, 160, 60);
+    pub const BLUE: Rgb = Rgb(40, 90, 200);
+    pub const YELLOW: Rgb = Rgb(240, 200, 60);
+    pub const GREY: Rgb = Rgb(110, 110, 110);
+
+    /// The colour `t` of the way from `self` to `to`, with `t` clamped to 0..=1.
+    pub fn mix(self, to: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, to.0),
+            channel(self.1, to.1),
+            channel(self.2, to.2),
+        )
+    }
+}
+
+/// Blows every pixel up into a `factor` by `factor` square.
+pub fn scale(image: &Grid<Rgb>, factor: usize) -> Grid<Rgb> {
+    let factor = factor.max(1);
+    let mut scaled = Grid::new(image.width() * factor, image.height() * factor, Rgb::BLACK);
+    for pos in scaled.positions() {
+        scaled[pos] = image[Pos::new(pos.x / factor, pos.y / factor)];
+    }
+    scaled
+}
+
+/// Writes the image as a binary PPM (P6).
+pub fn write_ppm(image: &Grid<Rgb>, mut out: impl Write) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", image.width(), image.height())?;
+    out.write_all(&bytes(image))?;
+    out.flush()
+}
+
+/// Writes the image as an 8 bit RGB PNG.
+#[cfg(feature = "png")]
+pub fn write_png(image: &Grid<Rgb>, out: impl Write) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(out, image.width() as u32, image.height() as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&bytes(image))?;
+    writer.finish()?;
+    Ok(())
+}
+
+fn bytes(image: &Grid<Rgb>) -> Vec<u8> {
+    image
+        .iter()
+        .flat_map(|(_, &Rgb(r, g, b))| [r, g, b])
+        .collect()
+}
+
+/// Saves the image scaled up by `factor`, as PNG or PPM depending on the
+/// extension of `path`.
+pub fn save(image: &Grid<Rgb>, path: &Path, factor: usize) -> io::Result<()> {
+    let image = scale(image, factor);
+    let extension = path.extension().and_then(|e| e.to_str());
+
+    match extension {
+        Some("ppm") => write_ppm(&image, BufWriter::new(File::create(path)?)),
+        #[cfg(feature = "png")]
+        Some("png") => write_png(&image, BufWriter::new(File::create(path)?)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported image format '{}'", path.display()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ppm_test() {
+        let mut image = Grid::new(2, 1, Rgb::BLACK);
+        image[Pos::new(1, 0)] = Rgb(1, 2, 3);
+
+        let mut out = vec![];
+        write_ppm(&scale(&image, 2), &mut out).unwrap();
+
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        for _ in 0..2 {
+            expected.extend([0, 0, 0, 0, 0, 0, 1, 2, 3, 1, 2, 3]);
+        }
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn mix_test() {
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 2.0), Rgb::WHITE);
+    }
+}
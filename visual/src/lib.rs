@@ -0,0 +1,84 @@
+pub mod image;
+#[cfg(feature = "terminal")]
+mod terminal;
+
+pub use image::Rgb;
+#[cfg(feature = "terminal")]
+pub use terminal::Terminal;
+
+use std::ops::ControlFlow;
+
+/// One picture of an animation: rows of text with a caption under them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Frame {
+    pub rows: Vec<String>,
+    pub caption: String,
+}
+
+impl Frame {
+    /// Makes a frame from text, one row per line.
+    pub fn new(text: &str) -> Frame {
+        Frame {
+            rows: text.lines().map(String::from).collect(),
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    /// The rows followed by the caption, as drawn on screen.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.rows
+            .iter()
+            .map(String::as_str)
+            .chain([self.caption.as_str()])
+    }
+
+    /// Indices of the lines that differ from `previous`, including lines
+    /// only one of the two frames has.
+    pub fn changed_lines(&self, previous: &Frame) -> Vec<usize> {
+        let (lines, previous_lines): (Vec<&str>, Vec<&str>) =
+            (self.lines().collect(), previous.lines().collect());
+
+        (0..lines.len().max(previous_lines.len()))
+            .filter(|&i| lines.get(i) != previous_lines.get(i))
+            .collect()
+    }
+}
+
+/// Somewhere to show the frames of an animation.
+pub trait Canvas {
+    /// Shows the next frame. Breaks when the viewer wants the animation to stop.
+    fn show(&mut self, frame: &Frame) -> ControlFlow<()>;
+}
+
+/// A canvas that keeps every frame it's shown.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    pub frames: Vec<Frame>,
+}
+
+impl Canvas for Recorder {
+    fn show(&mut self, frame: &Frame) -> ControlFlow<()> {
+        self.frames.push(frame.clone());
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changed_lines_test() {
+        let previous = Frame::new("..#\n...").with_caption("step 1");
+        let frame = Frame::new("..#\n.#.\n...").with_caption("step 2");
+
+        // The caption moves down a line, so both its old and new line change
+        assert_eq!(frame.changed_lines(&previous), vec![1, 2, 3]);
+        assert_eq!(frame.changed_lines(&frame), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{Canvas, Frame};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::io::{self, Stdout, Write};
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+
+// How often to check for keys while paused
+const PAUSED_POLL: Duration = Duration::from_millis(100);
+
+/// Plays frames in the terminal, redrawing only the lines that changed.
+///
+/// Space pauses and resumes, `n` or the right arrow steps one frame while
+/// paused and `q`, Esc or Ctrl-C stops. Frames wider or taller than the
+/// terminal are cropped around their middle column and to their top rows.
+pub struct Terminal {
+    out: Stdout,
+    frame_time: Duration,
+    last_draw: Option<Instant>,
+    previous: Option<Frame>,
+    // Terminal size when the previous frame was drawn
+    size: Option<(usize, usize)>,
+    paused: bool,
+    stopped: bool,
+}
+
+enum Key {
+    Stop,
+    Pause,
+    Step,
+    Other,
+}
+
+fn read_key(timeout: Duration) -> io::Result<Option<Key>> {
+    if !event::poll(timeout)? {
+        return Ok(None);
+    }
+
+    let key = match event::read()? {
+        Event::Key(key) if key.kind == KeyEventKind::Press => key,
+        _ => return Ok(Some(Key::Other)),
+    };
+    Ok(Some(match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => Key::Stop,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Key::Stop,
+        KeyCode::Char(' ') => Key::Pause,
+        KeyCode::Char('n') | KeyCode::Right => Key::Step,
+        _ => Key::Other,
+    }))
+}
+
+impl Terminal {
+    /// Takes over the terminal until dropped, showing at most `fps` frames
+    /// a second. An `fps` of 0 draws frames as fast as they come.
+    pub fn new(fps: u32) -> io::Result<Terminal> {
+        let mut out = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(out, EnterAlternateScreen, Hide, Clear(ClearType::All))?;
+
+        let frame_time = match fps {
+            0 => Duration::ZERO,
+            fps => Duration::from_secs(1) / fps,
+        };
+        Ok(Terminal {
+            out,
+            frame_time,
+            last_draw: None,
+            previous: None,
+            size: None,
+            paused: false,
+            stopped: false,
+        })
+    }
+
+    /// Keeps the last frame on screen until the viewer stops.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(frame) = self.previous.clone() {
+            let caption = format!("{} (finished, q to quit)", frame.caption);
+            self.draw(&frame.with_caption(caption.trim_start()))?;
+        }
+
+        while !self.stopped {
+            if let Some(Key::Stop) = read_key(PAUSED_POLL)? {
+                self.stopped = true;
+            }
+        }
+        Ok(())
+    }
+
+    // Waits until the next frame is due, handling keys in the meantime.
+    // Returns false if the viewer stopped
+    fn wait(&mut self) -> io::Result<bool> {
+        let due = self
+            .last_draw
+            .map_or_else(Instant::now, |last_draw| last_draw + self.frame_time);
+
+        loop {
+            let timeout = if self.paused {
+                PAUSED_POLL
+            } else {
+                due.saturating_duration_since(Instant::now())
+            };
+
+            match read_key(timeout)? {
+                Some(Key::Stop) => return Ok(false),
+                Some(Key::Pause) => self.paused = !self.paused,
+                Some(Key::Step) if self.paused => return Ok(true),
+                Some(_) => {}
+                None if !self.paused => return Ok(true),
+                None => {}
+            }
+        }
+    }
+
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+
+        // Redraw everything if the terminal was resized
+        let changed = match &self.previous {
+            Some(previous) if self.size == Some((width, height)) => frame.changed_lines(previous),
+            _ => {
+                queue!(self.out, Clear(ClearType::All))?;
+                (0..frame.lines().count()).collect()
+            }
+        };
+
+        let lines: Vec<&str> = frame.lines().collect();
+        let frame_width = frame.rows.iter().map(|row| row.chars().count()).max();
+        let skip = frame_width.unwrap_or(0).saturating_sub(width) / 2;
+
+        for y in changed.into_iter().filter(|&y| y < height) {
+            let line = match lines.get(y) {
+                // The caption isn't cropped around the middle
+                Some(&line) if y == lines.len() - 1 => line.chars().take(width).collect(),
+                Some(line) => line.chars().skip(skip).take(width).collect(),
+                None => String::new(),
+            };
+            queue!(
+                self.out,
+                MoveTo(0, y as u16),
+                Print(line),
+                Clear(ClearType::UntilNewLine)
+            )?;
+        }
+        self.out.flush()?;
+
+        self.size = Some((width, height));
+        self.previous = Some(frame.clone());
+        self.last_draw = Some(Instant::now());
+        Ok(())
+    }
+}
+
+impl Canvas for Terminal {
+    fn show(&mut self, frame: &Frame) -> ControlFlow<()> {
+        let shown = self.wait().and_then(|go_on| {
+            if go_on {
+                self.draw(frame)?;
+            }
+            Ok(go_on)
+        });
+
+        match shown {
+            Ok(true) => ControlFlow::Continue(()),
+            _ => {
+                self.stopped = true;
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = execute!(self.out, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}